@@ -0,0 +1,54 @@
+use std::str::FromStr;
+
+/// Indicate how MiniGrep searches a binary file, like an executable or an image.
+///
+/// A file is binary if the start of the file contains a NUL byte, or if more than
+/// one byte out of [`BinaryPolicy::CONTROL_RATIO`] is a control character other
+/// than a whitespace or an escape. The policy is chosen for each file, when many
+/// files are searched.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum BinaryPolicy {
+    /// Search the binary file, and print only a message like
+    /// `Binary file path matches`, in place of its found lines.
+    #[default]
+    Report,
+    /// Do not search the binary file, as if it did not contain the pattern.
+    Skip,
+    /// Search the binary file as a text file.
+    Text,
+}
+
+impl BinaryPolicy {
+    /// The ratio of bytes of a binary file that are control characters: a file is
+    /// binary if more than one byte out of this ratio is a control character.
+    pub const CONTROL_RATIO: usize = 10;
+
+    /// Check if the start of a file is the start of a binary file.
+    pub fn is_binary(start: &[u8]) -> bool {
+        if start.contains(&0) {
+            return true;
+        }
+
+        let controls = start
+            .iter()
+            .filter(|&&byte| byte.is_ascii_control() && !byte.is_ascii_whitespace() && byte != 0x1b)
+            .count();
+
+        controls * Self::CONTROL_RATIO > start.len()
+    }
+}
+
+impl FromStr for BinaryPolicy {
+    type Err = ();
+
+    /// Parse the policy with the values of grep: `binary`, `without-match` or
+    /// `text`.
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "binary" => Ok(Self::Report),
+            "without-match" => Ok(Self::Skip),
+            "text" => Ok(Self::Text),
+            _ => Err(()),
+        }
+    }
+}
@@ -0,0 +1,180 @@
+use std::str::FromStr;
+
+use super::context::LineKind;
+use super::matching::{InvalidUtf8, Submatch};
+
+/// Indicate when MiniGrep colors its output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColorChoice {
+    /// Color the output only if stdout is a terminal, and if the environment
+    /// variable `NO_COLOR` is not set.
+    #[default]
+    Auto,
+    /// Always color the output.
+    Always,
+    /// Never color the output.
+    Never,
+}
+
+impl FromStr for ColorChoice {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "auto" => Ok(Self::Auto),
+            "always" => Ok(Self::Always),
+            "never" => Ok(Self::Never),
+            _ => Err(()),
+        }
+    }
+}
+
+/// The styles used to color the output, as SGR parameters like `01;31`. An empty
+/// style leaves the text unchanged.
+///
+/// The default styles are the default styles of grep. They can be changed with a
+/// specification following the syntax of the `GREP_COLORS` variable of grep, see
+/// [`Colors::from_str()`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Colors {
+    /// The style of the matched text in selected lines.
+    pub selected_match: String,
+    /// The style of the matched text in context lines.
+    pub context_match: String,
+    /// The style of the whole selected lines.
+    pub selected_line: String,
+    /// The style of the whole context lines.
+    pub context_line: String,
+    /// The style of file paths.
+    pub filename: String,
+    /// The style of line numbers.
+    pub line_no: String,
+    /// The style of separators, like `:`, `-` and `--`.
+    pub separator: String,
+}
+
+impl Default for Colors {
+    fn default() -> Self {
+        Self {
+            selected_match: "01;31".to_owned(),
+            context_match: "01;31".to_owned(),
+            selected_line: String::new(),
+            context_line: String::new(),
+            filename: "35".to_owned(),
+            line_no: "32".to_owned(),
+            separator: "36".to_owned(),
+        }
+    }
+}
+
+impl FromStr for Colors {
+    type Err = String;
+
+    /// Parse a specification following the syntax of the `GREP_COLORS` variable of
+    /// grep, like `ms=01;32:ln=33:fn=`, over the default styles.
+    ///
+    /// The capabilities are `ms` (selected match), `mc` (context match), `mt` (both
+    /// matches), `sl` (selected line), `cx` (context line), `fn` (path), `ln` (line
+    /// number) and `se` (separator). Other capabilities of grep are ignored.
+    ///
+    /// # Returns
+    ///
+    /// Returns the parsed [`Colors`], or the first invalid entry if a style is not
+    /// a list of numbers separated by `;`.
+    fn from_str(spec: &str) -> Result<Self, Self::Err> {
+        let mut colors = Self::default();
+
+        for entry in spec.split(':').filter(|entry| !entry.is_empty()) {
+            let Some((capability, style)) = entry.split_once('=') else {
+                continue;
+            };
+
+            if !style
+                .chars()
+                .all(|char| char.is_ascii_digit() || char == ';')
+            {
+                return Err(entry.to_owned());
+            }
+
+            let style = style.to_owned();
+            match capability {
+                "ms" => colors.selected_match = style,
+                "mc" => colors.context_match = style,
+                "mt" => {
+                    colors.selected_match = style.clone();
+                    colors.context_match = style;
+                }
+                "sl" => colors.selected_line = style,
+                "cx" => colors.context_line = style,
+                "fn" => colors.filename = style,
+                "ln" => colors.line_no = style,
+                "se" => colors.separator = style,
+                _ => {}
+            }
+        }
+
+        Ok(colors)
+    }
+}
+
+impl Colors {
+    /// Wrap the text with the SGR sequences of the style.
+    ///
+    /// # Returns
+    ///
+    /// Returns the colored text, or the text unchanged if the style is empty.
+    pub fn paint(style: &str, text: &str) -> String {
+        if style.is_empty() || text.is_empty() {
+            text.to_owned()
+        } else {
+            format!("\x1b[{style}m{text}\x1b[m")
+        }
+    }
+
+    /// Wrap the bytes with the SGR sequences of the style, like [`Colors::paint()`].
+    pub fn paint_bytes(style: &str, bytes: &[u8]) -> Vec<u8> {
+        if style.is_empty() || bytes.is_empty() {
+            bytes.to_vec()
+        } else {
+            [format!("\x1b[{style}m").as_bytes(), bytes, b"\x1b[m"].concat()
+        }
+    }
+
+    /// Color the line, and its matched text, with the bytes that are not valid UTF-8
+    /// decoded with the [`InvalidUtf8`] mode.
+    ///
+    /// # Returns
+    ///
+    /// Returns the colored line. Empty matched texts and matched texts overlapping
+    /// a previous one are ignored.
+    pub fn paint_line(
+        &self,
+        kind: LineKind,
+        line: &[u8],
+        submatches: &[Submatch],
+        invalid_utf8: InvalidUtf8,
+    ) -> String {
+        let (line_style, match_style) = match kind {
+            LineKind::Match => (&self.selected_line, &self.selected_match),
+            LineKind::Context => (&self.context_line, &self.context_match),
+        };
+
+        let mut painted = String::with_capacity(line.len());
+        let mut end = 0;
+        for Submatch { range, .. } in submatches {
+            if range.is_empty() || range.start < end {
+                continue;
+            }
+
+            let before = invalid_utf8.decode(&line[end..range.start]);
+            let text = invalid_utf8.decode(&line[range.clone()]);
+            painted.push_str(&Self::paint(line_style, &before));
+            painted.push_str(&Self::paint(match_style, &text));
+            end = range.end;
+        }
+        let after = invalid_utf8.decode(&line[end..]);
+        painted.push_str(&Self::paint(line_style, &after));
+
+        painted
+    }
+}
@@ -0,0 +1,811 @@
+use std::convert::TryFrom;
+use std::env;
+use std::env::{Args, ArgsOs};
+use std::ffi::OsString;
+use std::fmt::{Debug, Display, Formatter};
+use std::io::{self, IsTerminal, Read, Write};
+use std::path::Path;
+
+use super::binary::BinaryPolicy;
+use super::color::{ColorChoice, Colors};
+use super::encoding::Encoding;
+use super::errors::{InvalidArgumentError, InvalidSyntaxError, MiniGrepArgsError, ReadError};
+use super::input::Input;
+use super::matching::{ColumnUnit, InvalidUtf8, Match};
+use super::options::{self, Arg, CliOption};
+use super::pattern::PatternSyntax;
+use super::printer::{BannerPrinter, JsonPrinter, OnlyMatchingPrinter, Printer, SummaryPrinter};
+use super::searcher::Searcher;
+use super::sink::Sink;
+
+/// Indicate how MiniGrep prints found lines.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum OutputFormat {
+    /// Print only found lines, like `line_no:line`, or `path:line_no:line` if many
+    /// files are searched. Nothing is printed if no line is found.
+    #[default]
+    Plain,
+    /// Print a sentence introducing found lines, like `line_no: line`, or a
+    /// sentence indicating that no line is found.
+    Banner,
+    /// Print a JSON object per line, for each event of the search: the begin of a
+    /// file, a found line, a context line, the end of a file, and the summary of
+    /// the search. The [`Report`] of the command is ignored.
+    Json,
+    /// Print each matched text of found lines like `path:line_no:column:line`, for
+    /// editors like Vim. Context lines are not printed, and a line selected by an
+    /// inverted match is printed once, with the column 1.
+    Vimgrep,
+}
+
+/// Indicate what MiniGrep reports for each searched file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Report {
+    /// Print found lines, with the [`OutputFormat`] of the command.
+    #[default]
+    Lines,
+    /// Print the number of found lines, like `count`, or `path:count` if many files
+    /// are searched.
+    Count,
+    /// Print the path of each file containing at least one found line. The read of
+    /// a file stops at its first found line.
+    FilesWithMatches,
+    /// Print the path of each file not containing any found line. The read of a
+    /// file stops at its first found line.
+    FilesWithoutMatch,
+}
+
+/// The exit status of MiniGrep, following the grep convention.
+///
+/// The category of an error stopping MiniGrep is given by
+/// [`MiniGrepArgsError::code()`], and not by the exit status.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ExitStatus {
+    /// At least one line is found, and no error has occurred.
+    Found,
+    /// No line is found, and no error has occurred.
+    NotFound,
+    /// An error has occurred, even if lines are found.
+    Error,
+}
+
+impl ExitStatus {
+    /// Get the exit code of MiniGrep.
+    ///
+    /// # Returns
+    ///
+    /// - 0: If at least one line is found, and no error has occurred.
+    /// - 1: If no line is found, and no error has occurred.
+    /// - 2: If an error has occurred.
+    pub fn code(self) -> i32 {
+        match self {
+            Self::Found => 0,
+            Self::NotFound => 1,
+            Self::Error => 2,
+        }
+    }
+
+    /// Get the exit status of a search.
+    #[doc(hidden)]
+    fn of_search(found: bool, all_read: bool) -> Self {
+        match (found, all_read) {
+            (_, false) => Self::Error,
+            (true, true) => Self::Found,
+            (false, true) => Self::NotFound,
+        }
+    }
+}
+
+/// All settings of a search, given on CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[doc(hidden)]
+struct Settings {
+    #[doc(hidden)]
+    output_format: OutputFormat,
+    #[doc(hidden)]
+    report: Report,
+    #[doc(hidden)]
+    only_matching: bool,
+    #[doc(hidden)]
+    column: bool,
+    #[doc(hidden)]
+    column_unit: ColumnUnit,
+    #[doc(hidden)]
+    invalid_utf8: InvalidUtf8,
+    #[doc(hidden)]
+    byte_offset: bool,
+    #[doc(hidden)]
+    color: ColorChoice,
+}
+
+/// The MiniGrep command to search each line that contains the pattern, in the
+/// given files, recursively in the given directories, or in the standard input.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::env::args_os;
+/// use std::process;
+///
+/// use mini_grep::Command;
+///
+/// let status = Command::try_from(args_os())
+///     .unwrap_or_else(|error| {
+///         eprintln!("error {}: {error}", error.code());
+///         process::exit(error.exit_code());
+///     })
+///     .execute();
+///
+/// process::exit(status.code());
+/// ```
+#[derive(Debug)]
+pub struct Command {
+    #[doc(hidden)]
+    searcher: Searcher,
+    #[doc(hidden)]
+    inputs: Vec<Input>,
+    #[doc(hidden)]
+    settings: Settings,
+}
+
+impl Command {
+    /// The environment variable name used to activate the case-insensitive pattern.
+    pub const IGNORE_CASE_ENV_NAME: &'static str = "IGNORE_CASE";
+
+    /// The environment variable name used to interpret the pattern as a regular
+    /// expression.
+    pub const REGEX_ENV_NAME: &'static str = "REGEX";
+
+    /// The environment variable name used to disable the colored output, if the
+    /// color is not forced on CLI.
+    pub const NO_COLOR_ENV_NAME: &'static str = "NO_COLOR";
+
+    /// The environment variable name used to change the colors of the output, with
+    /// the syntax of grep, like `ms=01;32:ln=33`.
+    pub const GREP_COLORS_ENV_NAME: &'static str = "GREP_COLORS";
+
+    /// All environment variables read by MiniGrep, and their description printed in
+    /// the help.
+    #[doc(hidden)]
+    const ENV_VARS: &'static [(&'static str, &'static str)] = &[
+        (
+            Self::IGNORE_CASE_ENV_NAME,
+            "Search the pattern case-insensitively if set to 'true' or '1'.",
+        ),
+        (
+            Self::REGEX_ENV_NAME,
+            "Interpret the pattern as a regular expression if set to 'true' or '1'.",
+        ),
+        (
+            Self::NO_COLOR_ENV_NAME,
+            "Do not color the output if set to a non-empty value, unless forced.",
+        ),
+        (
+            Self::GREP_COLORS_ENV_NAME,
+            "Change the colors of the output, like 'ms=01;32:mc=01;32:ln=33:fn=34:se=36'.",
+        ),
+    ];
+
+    /// All accepted values to activate the case-insensitive or the regular
+    /// expression pattern modes.
+    #[doc(hidden)]
+    const TRUE_VALUES: &'static [&'static str] = &["true", "1"];
+
+    /// Execute the MiniGrep command.
+    ///
+    /// Print to stdout found lines in the given files, or in the standard input,
+    /// that contains the given pattern, with the [`OutputFormat`] of the command.
+    /// Each found line is printed as soon as it is found. If many files are
+    /// searched, each found line is prefixed with the path of its file.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ExitStatus`] of the search.
+    ///
+    /// # Read errors
+    ///
+    /// Print to stderr an error message if a line, a file or a directory cannot be
+    /// read and continue the search. Stop the search if stdout is closed, and print
+    /// to stderr an error message if stdout cannot be written for another reason.
+    pub fn execute(&self) -> ExitStatus {
+        self.execute_to(self.sink(io::stdout()))
+    }
+
+    /// Get the [`Searcher`] of the command, searching the pattern in each file.
+    pub fn searcher(&self) -> &Searcher {
+        &self.searcher
+    }
+
+    /// Search in the reader, in place of the paths given on CLI, with the same
+    /// options. Found lines are prefixed with the `name` of the reader, like the
+    /// path of a file, if they are prefixed.
+    ///
+    /// The reader is read once, by the first execution of the command.
+    ///
+    /// # Examples
+    ///
+    /// ```rust
+    /// use mini_grep::{Command, ExitStatus};
+    ///
+    /// let args = ["mini-grep", "--count", "is"].map(String::from);
+    /// let command = Command::try_from_iter(args)
+    ///     .unwrap()
+    ///     .with_reader("text", "Rust is fast.\nC is old.\n".as_bytes());
+    ///
+    /// assert_eq!(command.execute(), ExitStatus::Found);
+    /// ```
+    pub fn with_reader(mut self, name: impl Into<String>, reader: impl Read + 'static) -> Command {
+        self.inputs = vec![Input::reader(name.into(), reader)];
+        self
+    }
+
+    /// Execute the MiniGrep command, and send each event of the search to the sink.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`ExitStatus`] of the search.
+    #[doc(hidden)]
+    fn execute_to(&self, mut sink: Box<dyn Sink>) -> ExitStatus {
+        // With the JSON output, found lines are always printed.
+        let report = match self.settings.output_format {
+            OutputFormat::Json => Report::Lines,
+            _ => self.settings.report,
+        };
+        let mut tracker = Tracker::new(sink.as_mut(), report);
+
+        let mut written = Ok(());
+        for input in &self.inputs {
+            written = input.for_each_file(&mut |filename, reader| match reader {
+                Ok(reader) => self.searcher.search_to(filename, reader, &mut tracker),
+                Err(error) => tracker.open_error(filename, error),
+            });
+            if written.is_err() {
+                break;
+            }
+        }
+
+        match written.and_then(|()| tracker.finish()) {
+            Err(error) if error.kind() != io::ErrorKind::BrokenPipe => {
+                tracker.all_read = false;
+                eprintln!("Cannot write the output, due to this error {error}.");
+            }
+            _ => {}
+        }
+
+        ExitStatus::of_search(tracker.found, tracker.all_read)
+    }
+
+    /// Indicate that each printed line is prefixed with the path of its file,
+    /// because many files are searched.
+    #[doc(hidden)]
+    fn with_filename(&self) -> bool {
+        !matches!(
+            self.inputs.as_slice(),
+            [Input::File(_) | Input::Stdin | Input::Reader { .. }]
+        )
+    }
+
+    /// Build the [`Sink`] printing the results of the search to the writer, with the
+    /// [`OutputFormat`] and the [`Report`] of the command.
+    ///
+    /// Lines are prefixed with the path of their file if many files are searched,
+    /// and groups of lines are separated if context lines are printed.
+    #[doc(hidden)]
+    fn sink(&self, writer: impl Write + 'static) -> Box<dyn Sink> {
+        let format = self.settings.output_format;
+        if format == OutputFormat::Json {
+            return Box::new(JsonPrinter::new(writer));
+        }
+
+        let with_context = (self.searcher.before_context() > 0
+            || self.searcher.after_context() > 0)
+            && !self.settings.only_matching;
+
+        let printer = Printer::new(
+            writer,
+            format,
+            self.with_filename(),
+            with_context,
+            self.is_colored().then(Self::colors_from_env),
+            (self.settings.column || self.settings.only_matching)
+                .then_some(self.settings.column_unit),
+            self.settings.byte_offset,
+        )
+        .with_invalid_utf8(self.settings.invalid_utf8);
+
+        if self.settings.report != Report::Lines {
+            return Box::new(SummaryPrinter::new(printer, self.settings.report));
+        }
+
+        if format == OutputFormat::Banner {
+            let input = match self.inputs.as_slice() {
+                [input @ (Input::File(_) | Input::Stdin | Input::Reader { .. })] => {
+                    Some(input.to_string())
+                }
+                _ => None,
+            };
+            let with = if self.searcher.is_inverted() {
+                "without"
+            } else {
+                "with"
+            };
+            let is_case_sensitive = if self.searcher.is_case_sensitive() {
+                "sensitive"
+            } else {
+                "insensitive"
+            };
+            let pattern = format!(
+                "{with} the case {is_case_sensitive} pattern '{}'",
+                self.searcher.pattern(),
+            );
+
+            let only_matching = self.settings.only_matching;
+            return Box::new(BannerPrinter::new(printer, only_matching, input, pattern));
+        }
+
+        if self.settings.only_matching {
+            return Box::new(OnlyMatchingPrinter::new(printer));
+        }
+
+        Box::new(printer)
+    }
+
+    /// Indicate that the output is colored, with the [`ColorChoice`] of the
+    /// command. With [`ColorChoice::Auto`], the output is colored only if stdout is
+    /// a terminal and the environment variable [`Command::NO_COLOR_ENV_NAME`] is
+    /// not set to a non-empty value.
+    #[doc(hidden)]
+    fn is_colored(&self) -> bool {
+        match self.settings.color {
+            ColorChoice::Always => true,
+            ColorChoice::Never => false,
+            ColorChoice::Auto => {
+                io::stdout().is_terminal()
+                    && env::var_os(Self::NO_COLOR_ENV_NAME).map_or(true, |value| value.is_empty())
+            }
+        }
+    }
+
+    /// Get the colors of the output from the environment variable
+    /// [`Command::GREP_COLORS_ENV_NAME`], or the default colors if it is not set.
+    ///
+    /// # Read errors
+    ///
+    /// Print to stderr an error message if the variable is invalid, and use the
+    /// default colors.
+    #[doc(hidden)]
+    fn colors_from_env() -> Colors {
+        let Ok(spec) = env::var(Self::GREP_COLORS_ENV_NAME) else {
+            return Colors::default();
+        };
+
+        spec.parse().unwrap_or_else(|entry| {
+            eprintln!(
+                "The entry '{entry}' of the variable '{}' is invalid, the default colors \
+                are used.",
+                Self::GREP_COLORS_ENV_NAME,
+            );
+
+            Colors::default()
+        })
+    }
+
+    /// Build a [`Command`]. The paths are checked and the files are opened only
+    /// during the search, one file at a time.
+    #[doc(hidden)]
+    fn build(searcher: Searcher, paths: Vec<OsString>, settings: Settings) -> Command {
+        let inputs = paths
+            .into_iter()
+            .map(|path| Input::build(path.into()))
+            .collect();
+
+        Self {
+            searcher,
+            inputs,
+            settings,
+        }
+    }
+
+    /// Build a [`Command`] from CLI arguments, like [`Command::try_from()`], the
+    /// first one being the name of the executable. The paths may not be valid
+    /// Unicode, but the options and the pattern must be.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`Command`], or a [`Box`] of [`MiniGrepArgsError`]
+    /// if an error has occurred during the extraction of CLI arguments or during the
+    /// check of preconditions about arguments, or an
+    /// [`InvalidSyntaxError::Missing`] if the iterator is empty. If `--help` or
+    /// `--version` is given, returns an [`InvalidSyntaxError::NotASearch`]: use
+    /// [`Parsed::try_from_iter()`] to get the text to print.
+    pub fn try_from_iter(
+        args: impl IntoIterator<Item = impl Into<OsString>>,
+    ) -> Result<Command, Box<dyn MiniGrepArgsError>> {
+        let mut args = args.into_iter().map(Into::into).peekable();
+        let executable = args
+            .peek()
+            .map_or(env!("CARGO_PKG_NAME").into(), |executable| {
+                executable.to_string_lossy().into_owned()
+            });
+
+        let option = match Self::parse(args)? {
+            Parsed::Run(command) => return Ok(command),
+            Parsed::Help(_) => "--help",
+            Parsed::Version(_) => "--version",
+        };
+        Err(Box::new(InvalidSyntaxError::NotASearch(
+            executable,
+            option.to_owned(),
+        )))
+    }
+
+    /// Parse CLI arguments, the first one being the name of the executable.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Parsed`] arguments, or a [`Box`] of [`MiniGrepArgsError`] like
+    /// [`Command::try_from_iter()`].
+    #[doc(hidden)]
+    fn parse(
+        args: impl IntoIterator<Item = impl Into<OsString>>,
+    ) -> Result<Parsed, Box<dyn MiniGrepArgsError>> {
+        let mut args = args.into_iter().map(Into::into);
+        let executable = match args.next() {
+            Some(executable) => executable.to_string_lossy().into_owned(),
+            None => {
+                let executable = env!("CARGO_PKG_NAME").to_owned();
+                return Err(Box::new(InvalidSyntaxError::Missing(executable)));
+            }
+        };
+
+        let mut pattern = None;
+        let mut paths = Vec::new();
+        let mut ignore_case = None;
+        let mut use_regex = None;
+        let mut invert_match = false;
+        let mut before_context = None;
+        let mut after_context = None;
+        let mut context = 0;
+        let mut binary = BinaryPolicy::default();
+        let mut encoding = Encoding::default();
+        let mut settings = Settings::default();
+
+        let parsed_args = options::parse(&executable, args)
+            .map_err(|error| Box::new(error) as Box<dyn MiniGrepArgsError>)?;
+
+        for arg in parsed_args {
+            match arg {
+                Arg::Option(CliOption::Regexp, _) if pattern.is_some() => {
+                    let option = "--regexp".to_owned();
+                    return Err(Box::new(InvalidSyntaxError::RepeatedOption(
+                        executable, option,
+                    )));
+                }
+                Arg::Option(CliOption::Regexp, value) => pattern = value,
+                Arg::Option(CliOption::ExtendedRegexp, _) => use_regex = Some(true),
+                Arg::Option(CliOption::FixedStrings, _) => use_regex = Some(false),
+                Arg::Option(CliOption::IgnoreCase, _) => ignore_case = Some(true),
+                Arg::Option(CliOption::NoIgnoreCase, _) => ignore_case = Some(false),
+                Arg::Option(CliOption::InvertMatch, _) => invert_match = true,
+                Arg::Option(CliOption::OnlyMatching, _) => settings.only_matching = true,
+                Arg::Option(option @ CliOption::AfterContext, value) => {
+                    after_context = Some(options::parse_value(&executable, option, value)?)
+                }
+                Arg::Option(option @ CliOption::BeforeContext, value) => {
+                    before_context = Some(options::parse_value(&executable, option, value)?)
+                }
+                Arg::Option(option @ CliOption::Context, value) => {
+                    context = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(CliOption::Count, _) => settings.report = Report::Count,
+                Arg::Option(CliOption::FilesWithMatches, _) => {
+                    settings.report = Report::FilesWithMatches
+                }
+                Arg::Option(CliOption::FilesWithoutMatch, _) => {
+                    settings.report = Report::FilesWithoutMatch
+                }
+                Arg::Option(option @ CliOption::Color, value) => {
+                    settings.color = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(CliOption::Banner, _) => settings.output_format = OutputFormat::Banner,
+                Arg::Option(CliOption::Json, _) => settings.output_format = OutputFormat::Json,
+                Arg::Option(CliOption::Vimgrep, _) => {
+                    settings.output_format = OutputFormat::Vimgrep
+                }
+                Arg::Option(CliOption::Column, _) => settings.column = true,
+                Arg::Option(option @ CliOption::ColumnUnit, value) => {
+                    settings.column_unit = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(CliOption::ByteOffset, _) => settings.byte_offset = true,
+                Arg::Option(option @ CliOption::InvalidUtf8, value) => {
+                    settings.invalid_utf8 = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(option @ CliOption::Encoding, value) => {
+                    encoding = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(option @ CliOption::BinaryFiles, value) => {
+                    binary = options::parse_value(&executable, option, value)?
+                }
+                Arg::Option(CliOption::Text, _) => binary = BinaryPolicy::Text,
+                Arg::Option(CliOption::Help, _) => {
+                    let help = options::help(&executable, Self::ENV_VARS);
+                    return Ok(Parsed::Help(help.trim_end().to_owned()));
+                }
+                Arg::Option(CliOption::Version, _) => {
+                    return Ok(Parsed::Version(options::version()))
+                }
+                Arg::Positional(arg) => paths.push(arg),
+            }
+        }
+
+        let pattern = match pattern {
+            Some(pattern) => pattern,
+            None if !paths.is_empty() => paths.remove(0).into_string().map_err(|pattern| {
+                InvalidSyntaxError::NotUnicode(
+                    executable.clone(),
+                    pattern.to_string_lossy().into_owned(),
+                )
+            })?,
+            None => return Err(Box::new(InvalidSyntaxError::Missing(executable))),
+        };
+
+        if paths.is_empty() {
+            paths.push(Input::STDIN_PATH.into());
+        }
+
+        let ignore_case = ignore_case.unwrap_or_else(Self::ignore_case_from_env);
+
+        let use_regex = use_regex.unwrap_or_else(|| {
+            env::var(Self::REGEX_ENV_NAME)
+                .is_ok_and(|value| Self::TRUE_VALUES.contains(&value.to_lowercase().as_str()))
+        });
+
+        let searcher = Searcher::builder(pattern)
+            .syntax(PatternSyntax::from(use_regex))
+            .case_sensitive(!ignore_case)
+            .invert_match(invert_match)
+            .before_context(before_context.unwrap_or(context))
+            .after_context(after_context.unwrap_or(context))
+            .binary(binary)
+            .encoding(encoding)
+            .build()?;
+
+        Ok(Parsed::Run(Self::build(searcher, paths, settings)))
+    }
+
+    /// Get the case-insensitive mode from the environment variable
+    /// [`Command::IGNORE_CASE_ENV_NAME`].
+    ///
+    /// # Read errors
+    ///
+    /// Print to stderr an error message if the variable cannot be read, and use
+    /// the case-sensitive mode.
+    #[doc(hidden)]
+    fn ignore_case_from_env() -> bool {
+        let ignore_case_env = env::var(Self::IGNORE_CASE_ENV_NAME);
+        if let Ok(value) = ignore_case_env {
+            Self::TRUE_VALUES.contains(&value.to_lowercase().as_str())
+        } else {
+            eprintln!(
+                "Error during the get of the variable '{}'. The error: '{}'.",
+                Self::IGNORE_CASE_ENV_NAME,
+                ignore_case_env.unwrap_err(),
+            );
+
+            false
+        }
+    }
+}
+
+/// Send each event of the search to the sink of the [`Command`], and track the
+/// [`ExitStatus`] of the search.
+#[doc(hidden)]
+struct Tracker<'a> {
+    #[doc(hidden)]
+    sink: &'a mut dyn Sink,
+    #[doc(hidden)]
+    report: Report,
+    #[doc(hidden)]
+    found: bool,
+    #[doc(hidden)]
+    file_found: bool,
+    #[doc(hidden)]
+    all_read: bool,
+}
+
+impl<'a> Tracker<'a> {
+    /// Build a [`Tracker`] of the sink. For [`Report::FilesWithoutMatch`], a file is
+    /// found if it does not contain any selected line.
+    #[doc(hidden)]
+    fn new(sink: &'a mut dyn Sink, report: Report) -> Tracker<'a> {
+        Self {
+            sink,
+            report,
+            found: false,
+            file_found: false,
+            all_read: true,
+        }
+    }
+}
+
+impl Sink for Tracker<'_> {
+    fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+        self.file_found = false;
+        self.sink.begin_file(path)
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        self.file_found = true;
+        self.found |= self.report != Report::FilesWithoutMatch;
+        self.sink.matched(path, found)
+    }
+
+    fn context(&mut self, path: &Path, line: Match) -> io::Result<()> {
+        self.sink.context(path, line)
+    }
+
+    fn binary_file(&mut self, path: &Path) -> io::Result<()> {
+        self.sink.binary_file(path)
+    }
+
+    /// Print to stderr the error of the line, and send it to the sink.
+    fn read_error(&mut self, path: &Path, error: ReadError) -> io::Result<()> {
+        self.all_read = false;
+
+        let ReadError::Line(line_number, cause) = &error;
+        eprintln!(
+            "Cannot read the line {line_number} from the file '{}', due to this error \
+            {cause}.",
+            path.display(),
+        );
+
+        self.sink.read_error(path, error)
+    }
+
+    /// Print to stderr the error of the path, prefixed with its code like the
+    /// errors of the arguments, and send it to the sink.
+    fn open_error(&mut self, path: &Path, error: InvalidArgumentError) -> io::Result<()> {
+        self.all_read = false;
+
+        eprintln!("error {}: {error}", error.code());
+
+        self.sink.open_error(path, error)
+    }
+
+    fn end_file(&mut self, path: &Path) -> io::Result<()> {
+        self.found |= self.report == Report::FilesWithoutMatch && !self.file_found;
+
+        self.sink.end_file(path)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        self.sink.finish()
+    }
+}
+
+impl TryFrom<Args> for Command {
+    type Error = Box<dyn MiniGrepArgsError>;
+
+    /// Build a [`Command`] from [`Args`].
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`Command`], or a [`Box`] of [`MiniGrepArgsError`]
+    /// if an error has occurred during the extraction of CLI arguments or during the
+    /// check of preconditions about arguments.
+    fn try_from(value: Args) -> Result<Command, Self::Error> {
+        Self::try_from_iter(value)
+    }
+}
+
+impl TryFrom<ArgsOs> for Command {
+    type Error = Box<dyn MiniGrepArgsError>;
+
+    /// Build a [`Command`] from [`ArgsOs`], that may not be valid Unicode.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`Command`], or a [`Box`] of [`MiniGrepArgsError`]
+    /// if an error has occurred during the extraction of CLI arguments or during the
+    /// check of preconditions about arguments.
+    fn try_from(value: ArgsOs) -> Result<Command, Self::Error> {
+        Self::try_from_iter(value)
+    }
+}
+
+/// The outcome of the parse of CLI arguments: the [`Command`] to execute, or the
+/// text to print in place of a search, on stdout and with the exit code 0.
+///
+/// # Examples
+///
+/// ```rust,no_run
+/// use std::env::args_os;
+/// use std::process;
+///
+/// use mini_grep::{ExitStatus, Parsed};
+///
+/// let status = match Parsed::try_from(args_os()) {
+///     Ok(Parsed::Run(command)) => command.execute(),
+///     Ok(Parsed::Help(text) | Parsed::Version(text)) => {
+///         println!("{text}");
+///         ExitStatus::Found
+///     }
+///     Err(error) => {
+///         eprintln!("error {}: {error}", error.code());
+///         process::exit(error.exit_code());
+///     }
+/// };
+///
+/// process::exit(status.code());
+/// ```
+#[derive(Debug)]
+pub enum Parsed {
+    /// The command to execute, searching the pattern.
+    Run(Command),
+    /// The help, asked with `--help`.
+    Help(String),
+    /// The version, asked with `--version`.
+    Version(String),
+}
+
+impl Parsed {
+    /// Parse CLI arguments, like [`Parsed::try_from()`], the first one being the
+    /// name of the executable. The paths may not be valid Unicode, but the options
+    /// and the pattern must be.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Command`] to execute, or the help or the version if `--help` or
+    /// `--version` is given, or a [`Box`] of [`MiniGrepArgsError`] like
+    /// [`Command::try_from_iter()`].
+    pub fn try_from_iter(
+        args: impl IntoIterator<Item = impl Into<OsString>>,
+    ) -> Result<Parsed, Box<dyn MiniGrepArgsError>> {
+        Command::parse(args)
+    }
+}
+
+impl TryFrom<Args> for Parsed {
+    type Error = Box<dyn MiniGrepArgsError>;
+
+    /// Parse [`Args`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Parsed`] arguments, or a [`Box`] of [`MiniGrepArgsError`] if an
+    /// error has occurred during the extraction of CLI arguments or during the check
+    /// of preconditions about arguments.
+    fn try_from(value: Args) -> Result<Parsed, Self::Error> {
+        Self::try_from_iter(value)
+    }
+}
+
+impl TryFrom<ArgsOs> for Parsed {
+    type Error = Box<dyn MiniGrepArgsError>;
+
+    /// Parse [`ArgsOs`], that may not be valid Unicode.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Parsed`] arguments, or a [`Box`] of [`MiniGrepArgsError`] if an
+    /// error has occurred during the extraction of CLI arguments or during the check
+    /// of preconditions about arguments.
+    fn try_from(value: ArgsOs) -> Result<Parsed, Self::Error> {
+        Self::try_from_iter(value)
+    }
+}
+
+impl Display for Command {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(
+            f,
+            "MiniGrep command searching the pattern '{}' in '{}'.",
+            self.searcher.pattern(),
+            self.inputs
+                .iter()
+                .map(|input| input.path().display().to_string())
+                .collect::<Vec<_>>()
+                .join("', '"),
+        )
+    }
+}
@@ -0,0 +1,96 @@
+use std::collections::VecDeque;
+
+/// Indicate why a line is yielded by a search.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum LineKind {
+    /// The line is selected by the pattern.
+    Match,
+    /// The line is printed around a selected line.
+    Context,
+}
+
+/// An [`Iterator`] adapter keeping selected lines, and the context lines before
+/// and after each of them.
+///
+/// Overlapping context windows are merged: each line is yielded at most once, in
+/// the order of the input. Only the last `before` not selected lines are kept in
+/// memory. An error is yielded as soon as it is read, after the pending lines.
+#[derive(Debug)]
+pub struct Context<I, L, E> {
+    #[doc(hidden)]
+    lines: I,
+    #[doc(hidden)]
+    before: usize,
+    #[doc(hidden)]
+    after: usize,
+    #[doc(hidden)]
+    remaining_after: usize,
+    #[doc(hidden)]
+    before_lines: VecDeque<L>,
+    #[doc(hidden)]
+    pending: VecDeque<Result<(LineKind, L), E>>,
+}
+
+impl<I, L, E> Context<I, L, E>
+where
+    I: Iterator<Item = Result<(L, bool), E>>,
+{
+    /// Build a [`Context`] from an [`Iterator`] of lines, with a flag indicating
+    /// that the line is selected, or of errors.
+    pub fn new(lines: I, before: usize, after: usize) -> Context<I, L, E> {
+        Self {
+            lines,
+            before,
+            after,
+            remaining_after: 0,
+            before_lines: VecDeque::with_capacity(before),
+            pending: VecDeque::new(),
+        }
+    }
+}
+
+impl<I, L, E> Iterator for Context<I, L, E>
+where
+    I: Iterator<Item = Result<(L, bool), E>>,
+{
+    type Item = Result<(LineKind, L), E>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(line) = self.pending.pop_front() {
+                return Some(line);
+            }
+
+            let (line, selected) = match self.lines.next()? {
+                Ok(line) => line,
+                Err(error) => return Some(Err(error)),
+            };
+
+            if selected {
+                self.pending.extend(
+                    self.before_lines
+                        .drain(..)
+                        .map(|line| Ok((LineKind::Context, line))),
+                );
+                self.pending.push_back(Ok((LineKind::Match, line)));
+                self.remaining_after = self.after;
+
+                continue;
+            }
+
+            if self.remaining_after > 0 {
+                self.remaining_after -= 1;
+
+                return Some(Ok((LineKind::Context, line)));
+            }
+
+            if self.before > 0 {
+                if self.before_lines.len() == self.before {
+                    self.before_lines.pop_front();
+                }
+
+                self.before_lines.push_back(line);
+            }
+        }
+    }
+}
@@ -0,0 +1,263 @@
+use std::io::{self, BufRead, Read};
+use std::mem;
+use std::str::FromStr;
+
+/// Indicate how MiniGrep decodes the text of a file, before searching it.
+///
+/// A file that is not UTF-8 is transcoded to UTF-8 while it is read, so its lines
+/// are searched and printed like the lines of an UTF-8 file.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Encoding {
+    /// Detect the encoding with the byte order mark (BOM) at the start of the file:
+    /// UTF-8, UTF-16LE or UTF-16BE, or UTF-8 if the file has no BOM.
+    #[default]
+    Auto,
+    /// Decode the file as UTF-8.
+    Utf8,
+    /// Decode the file as UTF-16, little-endian.
+    Utf16Le,
+    /// Decode the file as UTF-16, big-endian.
+    Utf16Be,
+    /// Decode the file as Latin-1 (ISO-8859-1), each byte being a character.
+    Latin1,
+}
+
+impl Encoding {
+    /// Detect the encoding of a file with the BOM at its start.
+    ///
+    /// # Returns
+    ///
+    /// Returns the detected encoding and the length of its BOM, or
+    /// [`Encoding::Utf8`] and `0` if the file has no BOM.
+    pub fn detect(start: &[u8]) -> (Encoding, usize) {
+        [Self::Utf8, Self::Utf16Le, Self::Utf16Be]
+            .into_iter()
+            .find(|encoding| start.starts_with(encoding.bom()))
+            .map_or((Self::Utf8, 0), |encoding| (encoding, encoding.bom().len()))
+    }
+
+    /// Get the BOM of the encoding, or an empty BOM if it has none.
+    pub fn bom(self) -> &'static [u8] {
+        match self {
+            Self::Utf8 => b"\xEF\xBB\xBF",
+            Self::Utf16Le => b"\xFF\xFE",
+            Self::Utf16Be => b"\xFE\xFF",
+            Self::Auto | Self::Latin1 => b"",
+        }
+    }
+}
+
+impl FromStr for Encoding {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value.to_lowercase().as_str() {
+            "auto" => Ok(Self::Auto),
+            "utf-8" | "utf8" => Ok(Self::Utf8),
+            "utf-16le" | "utf16le" => Ok(Self::Utf16Le),
+            "utf-16be" | "utf16be" => Ok(Self::Utf16Be),
+            "latin-1" | "latin1" | "iso-8859-1" => Ok(Self::Latin1),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A buffered reader transcoding to UTF-8 the text of a reader, decoded with its
+/// [`Encoding`].
+///
+/// The BOM at the start of the reader is skipped. An UTF-8 text is not copied, and
+/// is read from the buffer of the reader. An invalid UTF-16 sequence is replaced
+/// with the replacement character `�`.
+#[derive(Debug)]
+pub struct Decoder<R> {
+    #[doc(hidden)]
+    reader: R,
+    #[doc(hidden)]
+    encoding: Encoding,
+    #[doc(hidden)]
+    started: bool,
+    #[doc(hidden)]
+    decoded: Vec<u8>,
+    #[doc(hidden)]
+    position: usize,
+    #[doc(hidden)]
+    pending: Vec<u8>,
+}
+
+impl<R: BufRead> Decoder<R> {
+    /// Build a [`Decoder`] of the reader, from its start.
+    pub fn new(reader: R, encoding: Encoding) -> Decoder<R> {
+        Self {
+            reader,
+            encoding,
+            started: false,
+            decoded: Vec::new(),
+            position: 0,
+            pending: Vec::new(),
+        }
+    }
+
+    /// Skip the BOM at the start of the reader, and detect the encoding if it is
+    /// [`Encoding::Auto`]. The start of the reader is kept, if the reader gives
+    /// fewer bytes than the longest BOM at its first read.
+    #[doc(hidden)]
+    fn start(&mut self) -> io::Result<()> {
+        let longest_bom = Encoding::Utf8.bom().len();
+        while self.pending.len() < longest_bom {
+            let bytes = self.reader.fill_buf()?;
+            if bytes.is_empty() || (self.pending.is_empty() && bytes.len() >= longest_bom) {
+                break;
+            }
+
+            let read = bytes.len().min(longest_bom - self.pending.len());
+            self.pending.extend_from_slice(&bytes[..read]);
+            self.reader.consume(read);
+        }
+
+        let is_kept = !self.pending.is_empty();
+        let start = if is_kept {
+            &self.pending
+        } else {
+            self.reader.fill_buf()?
+        };
+        let (encoding, bom_len) = match self.encoding {
+            Encoding::Auto => Encoding::detect(start),
+            encoding if !encoding.bom().is_empty() && start.starts_with(encoding.bom()) => {
+                (encoding, encoding.bom().len())
+            }
+            encoding => (encoding, 0),
+        };
+
+        if is_kept {
+            self.pending.drain(..bom_len);
+        } else {
+            self.reader.consume(bom_len);
+        }
+        self.encoding = encoding;
+        self.started = true;
+
+        // The kept start of an UTF-8 text is read before the buffer of the reader.
+        if encoding == Encoding::Utf8 {
+            self.decoded = mem::take(&mut self.pending);
+        }
+
+        Ok(())
+    }
+
+    /// Decode the next bytes of the reader, until at least one character is decoded
+    /// or the end of the reader.
+    #[doc(hidden)]
+    fn decode_next(&mut self) -> io::Result<()> {
+        self.decoded.clear();
+        self.position = 0;
+
+        while self.decoded.is_empty() {
+            let bytes = self.reader.fill_buf()?;
+            let is_end = bytes.is_empty();
+            let read = bytes.len();
+            self.pending.extend_from_slice(bytes);
+            self.reader.consume(read);
+
+            match self.encoding {
+                Encoding::Utf16Le => self.decode_utf16(u16::from_le_bytes, is_end),
+                Encoding::Utf16Be => self.decode_utf16(u16::from_be_bytes, is_end),
+                Encoding::Latin1 => {
+                    for byte in self.pending.drain(..) {
+                        push_char(&mut self.decoded, char::from(byte));
+                    }
+                }
+                Encoding::Auto | Encoding::Utf8 => unreachable!("UTF-8 is not decoded."),
+            }
+
+            if is_end {
+                break;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Decode the pending UTF-16 code units. An incomplete code unit, or a high
+    /// surrogate at the end of the pending bytes, is kept until the next bytes,
+    /// and is replaced at the end of the reader.
+    #[doc(hidden)]
+    fn decode_utf16(&mut self, to_unit: fn([u8; 2]) -> u16, is_end: bool) {
+        let mut units: Vec<_> = self
+            .pending
+            .chunks_exact(2)
+            .map(|pair| to_unit([pair[0], pair[1]]))
+            .collect();
+
+        if !is_end
+            && units
+                .last()
+                .is_some_and(|unit| (0xD800..0xDC00).contains(unit))
+        {
+            units.pop();
+        }
+
+        for decoded in char::decode_utf16(units.iter().copied()) {
+            push_char(
+                &mut self.decoded,
+                decoded.unwrap_or(char::REPLACEMENT_CHARACTER),
+            );
+        }
+        self.pending.drain(..units.len() * 2);
+
+        if is_end && !self.pending.is_empty() {
+            self.pending.clear();
+            push_char(&mut self.decoded, char::REPLACEMENT_CHARACTER);
+        }
+    }
+}
+
+impl<R: BufRead> Read for Decoder<R> {
+    fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+        let decoded = self.fill_buf()?;
+        let read = decoded.len().min(buffer.len());
+        buffer[..read].copy_from_slice(&decoded[..read]);
+        self.consume(read);
+
+        Ok(read)
+    }
+}
+
+impl<R: BufRead> BufRead for Decoder<R> {
+    fn fill_buf(&mut self) -> io::Result<&[u8]> {
+        if !self.started {
+            self.start()?;
+        }
+
+        match self.encoding {
+            Encoding::Auto | Encoding::Utf8 if self.position < self.decoded.len() => {
+                Ok(&self.decoded[self.position..])
+            }
+            Encoding::Auto | Encoding::Utf8 => self.reader.fill_buf(),
+            Encoding::Utf16Le | Encoding::Utf16Be | Encoding::Latin1 => {
+                if self.position >= self.decoded.len() {
+                    self.decode_next()?;
+                }
+
+                Ok(&self.decoded[self.position..])
+            }
+        }
+    }
+
+    fn consume(&mut self, amount: usize) {
+        match self.encoding {
+            Encoding::Auto | Encoding::Utf8 if self.position < self.decoded.len() => {
+                self.position = (self.position + amount).min(self.decoded.len())
+            }
+            Encoding::Auto | Encoding::Utf8 => self.reader.consume(amount),
+            Encoding::Utf16Le | Encoding::Utf16Be | Encoding::Latin1 => {
+                self.position = (self.position + amount).min(self.decoded.len())
+            }
+        }
+    }
+}
+
+/// Push the character, encoded in UTF-8, to the bytes.
+#[doc(hidden)]
+fn push_char(bytes: &mut Vec<u8>, character: char) {
+    bytes.extend_from_slice(character.encode_utf8(&mut [0; 4]).as_bytes());
+}
@@ -0,0 +1,254 @@
+use std::error::Error;
+use std::fmt::{Debug, Display, Formatter};
+use std::io;
+use std::path::PathBuf;
+
+use super::command::ExitStatus;
+
+/// Indicate that [`Command::try_from()`](crate::Command::try_from) or
+/// [`SearcherBuilder::build()`](crate::SearcherBuilder::build) receives a bad
+/// argument from CLI.
+pub trait MiniGrepArgsError: Debug + Display + Error {
+    /// Get the code identifying the category of the error.
+    ///
+    /// This code is not the exit code of MiniGrep, see
+    /// [`MiniGrepArgsError::exit_code()`].
+    fn code(&self) -> i32;
+
+    /// Get the exit code used to stop the run of MiniGrep, always the code of
+    /// [`ExitStatus::Error`].
+    fn exit_code(&self) -> i32 {
+        ExitStatus::Error.code()
+    }
+}
+
+/// Indicate that [`Command::try_from()`](crate::Command::try_from) receives not
+/// enough arguments or a bad option from CLI.
+#[derive(Debug, Clone)]
+pub enum InvalidSyntaxError {
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives not
+    /// enough arguments from CLI.
+    Missing(String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives an
+    /// unknown option from CLI, with the closest known option if any.
+    UnknownOption(String, String, Option<String>),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives an
+    /// option without its value from CLI.
+    MissingValue(String, String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives a
+    /// value for an option that does not take one from CLI.
+    UnexpectedValue(String, String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives an
+    /// invalid value for an option from CLI.
+    InvalidValue(String, String, String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives an
+    /// option, its value or the pattern not being valid Unicode from CLI.
+    NotUnicode(String, String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives
+    /// many times an option that can be given only once from CLI.
+    RepeatedOption(String, String),
+    /// Indicate that [`Command::try_from()`](crate::Command::try_from) receives
+    /// `--help` or `--version` from CLI, that print a text in place of a search.
+    /// [`Parsed::try_from()`](crate::Parsed::try_from) accepts them.
+    NotASearch(String, String),
+}
+
+impl Display for InvalidSyntaxError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let (msg, executable) = match self {
+            Self::Missing(executable) => ("Missing arguments.".to_owned(), executable),
+            Self::UnknownOption(executable, option, Some(suggestion)) => (
+                format!("Unknown option '{option}'. Did you mean '{suggestion}'?"),
+                executable,
+            ),
+            Self::UnknownOption(executable, option, None) => {
+                (format!("Unknown option '{option}'."), executable)
+            }
+            Self::MissingValue(executable, option) => (
+                format!("The option '{option}' requires a value."),
+                executable,
+            ),
+            Self::UnexpectedValue(executable, option) => (
+                format!("The option '{option}' does not take a value."),
+                executable,
+            ),
+            Self::InvalidValue(executable, option, value) => (
+                format!("The value '{value}' of the option '{option}' is invalid."),
+                executable,
+            ),
+            Self::NotUnicode(executable, arg) => (
+                format!("The argument '{arg}' is not valid Unicode."),
+                executable,
+            ),
+            Self::RepeatedOption(executable, option) => (
+                format!("The option '{option}' is given many times."),
+                executable,
+            ),
+            Self::NotASearch(executable, option) => (
+                format!("The option '{option}' does not run a search."),
+                executable,
+            ),
+        };
+
+        write!(
+            f,
+            "{msg} Call the script like: {executable} [OPTIONS] pattern [path...], or \
+            see: {executable} --help",
+        )
+    }
+}
+
+impl Error for InvalidSyntaxError {}
+
+impl From<InvalidSyntaxError> for Box<dyn MiniGrepArgsError> {
+    fn from(value: InvalidSyntaxError) -> Self {
+        Box::new(value)
+    }
+}
+
+impl MiniGrepArgsError for InvalidSyntaxError {
+    /// Get the code identifying the category of the error.
+    ///
+    /// # Returns
+    ///
+    /// - 126: If not enough arguments are given to CLI.
+    /// - 127: If an unknown option is given to CLI.
+    /// - 128: If an option is given without its value to CLI.
+    /// - 129: If an option is given with an unexpected value to CLI.
+    /// - 136: If an option is given with an invalid value to CLI.
+    /// - 137: If an option, its value or the pattern is not valid Unicode.
+    /// - 138: If an option that can be given only once is repeated.
+    /// - 139: If `--help` or `--version` is given to build a [`Command`](crate::Command).
+    fn code(&self) -> i32 {
+        match self {
+            Self::Missing(_) => 126,
+            Self::UnknownOption(..) => 127,
+            Self::MissingValue(..) => 128,
+            Self::UnexpectedValue(..) => 129,
+            Self::InvalidValue(..) => 136,
+            Self::NotUnicode(..) => 137,
+            Self::RepeatedOption(..) => 138,
+            Self::NotASearch(..) => 139,
+        }
+    }
+}
+
+/// Indicate that [`Command::try_from()`](crate::Command::try_from),
+/// [`SearcherBuilder::build()`](crate::SearcherBuilder::build) or
+/// [`Searcher::search_path()`](crate::Searcher::search_path) receives a bad
+/// argument from CLI. An error of a path given to the [`Command`](crate::Command)
+/// is printed during its search, and the other paths are still searched.
+#[derive(Debug)]
+pub enum InvalidArgumentError {
+    /// Indicate that [`SearcherBuilder::build()`](crate::SearcherBuilder::build)
+    /// receives a blank pattern, from CLI.
+    BlankPattern(String),
+    /// Indicate that a path to neither a file nor a directory is searched, from CLI.
+    NotAFile(PathBuf, String),
+    /// Indicate that a path pointing to a not existing file is searched, from CLI.
+    FileNotFound(PathBuf),
+    /// Indicate that a relative path that cannot be converted to the absolute path
+    /// is searched, from CLI.
+    CannotResolvePath(PathBuf, std::io::Error),
+    /// Indicate that a path pointing to a not readable file is searched, from CLI.
+    NotAReadableFile(PathBuf, std::io::Error),
+    /// Indicate that a directory, or one of its entries, cannot be read during the
+    /// search of a path given to CLI.
+    NotAReadableDirectory(PathBuf, std::io::Error),
+    /// Indicate that [`SearcherBuilder::build()`](crate::SearcherBuilder::build)
+    /// receives a pattern that is not a valid regular expression, from CLI.
+    InvalidRegex(String, regex::Error),
+}
+
+impl Display for InvalidArgumentError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        let msg = match self {
+            Self::BlankPattern(pattern) => {
+                format!("Cannot have a blank searched text '{pattern}'.")
+            }
+            Self::NotAFile(filename, file_type) => {
+                format!(
+                    "'{}' is not a file, it is a {file_type}.",
+                    filename.display()
+                )
+            }
+            Self::FileNotFound(filename) => {
+                format!("The file '{}' does not exist.", filename.display())
+            }
+            Self::CannotResolvePath(filename, error) => format!(
+                "Cannot resolve the path ('{}') to the absolute path, due to this error \
+                {error}.",
+                filename.display(),
+            ),
+            Self::NotAReadableFile(filename, error) => format!(
+                "Cannot open the file '{}', due to this error {error}.",
+                filename.display(),
+            ),
+            Self::NotAReadableDirectory(directory, error) => format!(
+                "Cannot read the directory '{}', due to this error {error}.",
+                directory.display(),
+            ),
+            Self::InvalidRegex(pattern, error) => format!(
+                "The pattern '{pattern}' is not a valid regular expression, due to this \
+                error:\n{error}"
+            ),
+        };
+
+        write!(f, "{}", msg)
+    }
+}
+
+impl Error for InvalidArgumentError {}
+
+impl From<InvalidArgumentError> for Box<dyn MiniGrepArgsError> {
+    fn from(value: InvalidArgumentError) -> Self {
+        Box::new(value)
+    }
+}
+
+impl MiniGrepArgsError for InvalidArgumentError {
+    /// Get the code identifying the category of the error.
+    ///
+    /// # Returns
+    ///
+    /// - 130: If receives a blank pattern.
+    /// - 131: If receives a path pointing to anything else than a file or a
+    ///   directory.
+    /// - 132: If receives a path pointing to a not existing file.
+    /// - 133: If receives a relative path that cannot be resolved to an absolute path.
+    /// - 134: If receives a path to a not readable file.
+    /// - 135: If receives a pattern that is not a valid regular expression.
+    /// - 140: If receives a directory that cannot be read.
+    fn code(&self) -> i32 {
+        match self {
+            Self::BlankPattern(_) => 130,
+            Self::NotAFile(..) => 131,
+            Self::FileNotFound(_) => 132,
+            Self::CannotResolvePath(..) => 133,
+            Self::NotAReadableFile(..) => 134,
+            Self::InvalidRegex(..) => 135,
+            Self::NotAReadableDirectory(..) => 140,
+        }
+    }
+}
+
+/// Indicate that a line cannot be read during a search, by
+/// [`Searcher::search()`](crate::Searcher::search).
+#[derive(Debug)]
+pub enum ReadError {
+    /// Indicate that the line, with its number, cannot be read from the reader.
+    Line(usize, io::Error),
+}
+
+impl Display for ReadError {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Line(line_number, error) => write!(
+                f,
+                "Cannot read the line {line_number}, due to this error {error}."
+            ),
+        }
+    }
+}
+
+impl Error for ReadError {}
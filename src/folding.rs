@@ -0,0 +1,246 @@
+use std::cell::RefCell;
+use std::iter;
+use std::ops::Range;
+
+/// The full case foldings of Unicode that are not the lowercase mapping of the
+/// character, sorted by character, without the Cherokee letters and the Greek
+/// letters with a ypogegrammeni from `U+1F80` to `U+1FAF`, folded by
+/// [`fold_char()`].
+#[doc(hidden)]
+const FOLDINGS: &[(char, &str)] = &[
+    ('\u{00B5}', "\u{03BC}"),
+    ('\u{00DF}', "ss"),
+    ('\u{0149}', "\u{02BC}n"),
+    ('\u{017F}', "s"),
+    ('\u{01F0}', "j\u{030C}"),
+    ('\u{0345}', "\u{03B9}"),
+    ('\u{0390}', "\u{03B9}\u{0308}\u{0301}"),
+    ('\u{03B0}', "\u{03C5}\u{0308}\u{0301}"),
+    ('\u{03C2}', "\u{03C3}"),
+    ('\u{03D0}', "\u{03B2}"),
+    ('\u{03D1}', "\u{03B8}"),
+    ('\u{03D5}', "\u{03C6}"),
+    ('\u{03D6}', "\u{03C0}"),
+    ('\u{03F0}', "\u{03BA}"),
+    ('\u{03F1}', "\u{03C1}"),
+    ('\u{03F5}', "\u{03B5}"),
+    ('\u{0587}', "\u{0565}\u{0582}"),
+    ('\u{1C80}', "\u{0432}"),
+    ('\u{1C81}', "\u{0434}"),
+    ('\u{1C82}', "\u{043E}"),
+    ('\u{1C83}', "\u{0441}"),
+    ('\u{1C84}', "\u{0442}"),
+    ('\u{1C85}', "\u{0442}"),
+    ('\u{1C86}', "\u{044A}"),
+    ('\u{1C87}', "\u{0463}"),
+    ('\u{1C88}', "\u{A64B}"),
+    ('\u{1E96}', "h\u{0331}"),
+    ('\u{1E97}', "t\u{0308}"),
+    ('\u{1E98}', "w\u{030A}"),
+    ('\u{1E99}', "y\u{030A}"),
+    ('\u{1E9A}', "a\u{02BE}"),
+    ('\u{1E9B}', "\u{1E61}"),
+    ('\u{1E9E}', "ss"),
+    ('\u{1F50}', "\u{03C5}\u{0313}"),
+    ('\u{1F52}', "\u{03C5}\u{0313}\u{0300}"),
+    ('\u{1F54}', "\u{03C5}\u{0313}\u{0301}"),
+    ('\u{1F56}', "\u{03C5}\u{0313}\u{0342}"),
+    ('\u{1FB2}', "\u{1F70}\u{03B9}"),
+    ('\u{1FB3}', "\u{03B1}\u{03B9}"),
+    ('\u{1FB4}', "\u{03AC}\u{03B9}"),
+    ('\u{1FB6}', "\u{03B1}\u{0342}"),
+    ('\u{1FB7}', "\u{03B1}\u{0342}\u{03B9}"),
+    ('\u{1FBC}', "\u{03B1}\u{03B9}"),
+    ('\u{1FBE}', "\u{03B9}"),
+    ('\u{1FC2}', "\u{1F74}\u{03B9}"),
+    ('\u{1FC3}', "\u{03B7}\u{03B9}"),
+    ('\u{1FC4}', "\u{03AE}\u{03B9}"),
+    ('\u{1FC6}', "\u{03B7}\u{0342}"),
+    ('\u{1FC7}', "\u{03B7}\u{0342}\u{03B9}"),
+    ('\u{1FCC}', "\u{03B7}\u{03B9}"),
+    ('\u{1FD2}', "\u{03B9}\u{0308}\u{0300}"),
+    ('\u{1FD3}', "\u{03B9}\u{0308}\u{0301}"),
+    ('\u{1FD6}', "\u{03B9}\u{0342}"),
+    ('\u{1FD7}', "\u{03B9}\u{0308}\u{0342}"),
+    ('\u{1FE2}', "\u{03C5}\u{0308}\u{0300}"),
+    ('\u{1FE3}', "\u{03C5}\u{0308}\u{0301}"),
+    ('\u{1FE4}', "\u{03C1}\u{0313}"),
+    ('\u{1FE6}', "\u{03C5}\u{0342}"),
+    ('\u{1FE7}', "\u{03C5}\u{0308}\u{0342}"),
+    ('\u{1FF2}', "\u{1F7C}\u{03B9}"),
+    ('\u{1FF3}', "\u{03C9}\u{03B9}"),
+    ('\u{1FF4}', "\u{03CE}\u{03B9}"),
+    ('\u{1FF6}', "\u{03C9}\u{0342}"),
+    ('\u{1FF7}', "\u{03C9}\u{0342}\u{03B9}"),
+    ('\u{1FFC}', "\u{03C9}\u{03B9}"),
+    ('\u{FB00}', "ff"),
+    ('\u{FB01}', "fi"),
+    ('\u{FB02}', "fl"),
+    ('\u{FB03}', "ffi"),
+    ('\u{FB04}', "ffl"),
+    ('\u{FB05}', "st"),
+    ('\u{FB06}', "st"),
+    ('\u{FB13}', "\u{0574}\u{0576}"),
+    ('\u{FB14}', "\u{0574}\u{0565}"),
+    ('\u{FB15}', "\u{0574}\u{056B}"),
+    ('\u{FB16}', "\u{057E}\u{0576}"),
+    ('\u{FB17}', "\u{0574}\u{056D}"),
+];
+
+/// Fold the case of the character, with the full case folding of Unicode: like its
+/// lowercase mapping, except for some characters like `ß` folded to `ss`, the final
+/// sigma `ς` folded to `σ`, or the ligature `ﬁ` folded to `fi`.
+///
+/// # Returns
+///
+/// Returns the folded characters, one or more.
+pub fn fold_char(character: char) -> impl Iterator<Item = char> {
+    let code = u32::from(character);
+    let (folded, folding) = match code {
+        // The lowercase Cherokee letters are folded to the uppercase ones.
+        0x13A0..=0x13F5 => (Some(character), ""),
+        0x13F8..=0x13FD => (char::from_u32(code - 8), ""),
+        0xAB70..=0xABBF => (char::from_u32(code - 0xAB70 + 0x13A0), ""),
+        // The letter with a ypogegrammeni is folded to the letter and an iota.
+        0x1F80..=0x1FAF => {
+            let letter = [0x1F00, 0x1F20, 0x1F60][(code as usize - 0x1F80) / 16] + (code & 7);
+            (char::from_u32(letter), "\u{03B9}")
+        }
+        _ => match FOLDINGS.binary_search_by_key(&character, |&(folded, _)| folded) {
+            Ok(index) => (None, FOLDINGS[index].1),
+            Err(_) => (None, ""),
+        },
+    };
+
+    let lowercase = match (folded, folding) {
+        (None, "") => Some(character.to_lowercase()),
+        _ => None,
+    };
+
+    folded
+        .into_iter()
+        .chain(folding.chars())
+        .chain(lowercase.into_iter().flatten())
+}
+
+/// A text with its case folded by [`fold_char()`], keeping the original byte offset
+/// of each folded byte, to find in the folded text and give the byte ranges in the
+/// original text.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Folded {
+    /// The folded text.
+    pub text: String,
+    /// The byte offset in the original text of the character of each byte of the
+    /// folded text, and the length of the original text.
+    pub offsets: Vec<usize>,
+}
+
+impl Folded {
+    /// Build a [`Folded`] text, by folding each character of the text.
+    pub fn new(text: &str) -> Folded {
+        let mut folded = Self {
+            text: String::with_capacity(text.len()),
+            offsets: Vec::with_capacity(text.len() + 1),
+        };
+        folded.fold(text);
+
+        folded
+    }
+
+    /// Replace the folded text by the folding of the text, reusing its buffers.
+    #[doc(hidden)]
+    fn fold(&mut self, text: &str) {
+        self.text.clear();
+        self.offsets.clear();
+
+        for (offset, character) in text.char_indices() {
+            self.text.extend(fold_char(character));
+            self.offsets.resize(self.text.len(), offset);
+        }
+        self.offsets.push(text.len());
+    }
+
+    /// Call the function with the folding of the text. The last folded text of the
+    /// thread is kept, so a line searched again for its matched texts, once
+    /// selected, is folded only once.
+    ///
+    /// # Returns
+    ///
+    /// Returns the result of the function.
+    pub fn with<T>(text: &str, function: impl FnOnce(&Folded) -> T) -> T {
+        thread_local! {
+            /// The last folded text of the thread, and its folding.
+            static LAST: RefCell<(String, Folded)> =
+                RefCell::new((String::new(), Folded::new("")));
+        }
+
+        LAST.with(|last| {
+            let (original, folded) = &mut *last.borrow_mut();
+            if original != text {
+                original.clear();
+                original.push_str(text);
+                folded.fold(text);
+            }
+
+            function(folded)
+        })
+    }
+
+    /// Indicate that the byte offset of the folded text is the start or the end of
+    /// the folding of an original character.
+    pub fn is_boundary(&self, offset: usize) -> bool {
+        offset == 0 || offset == self.text.len() || self.offsets[offset] != self.offsets[offset - 1]
+    }
+
+    /// Find each occurrence of the folded pattern, starting and ending at the
+    /// boundaries of original characters, like `ss` in `ß`, but not `s` in `ß`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields the byte ranges in the original text of
+    /// all non-overlapping occurrences, in order.
+    pub fn find_iter<'a>(&'a self, pattern: &'a str) -> impl Iterator<Item = Range<usize>> + 'a {
+        let mut from = 0;
+
+        iter::from_fn(move || {
+            while from <= self.text.len() {
+                let start = from + self.text[from..].find(pattern)?;
+                let end = start + pattern.len();
+                from = start + self.text[start..].chars().next().map_or(1, char::len_utf8);
+
+                if self.is_boundary(start) && self.is_boundary(end) {
+                    from = from.max(end);
+                    return Some(self.offsets[start]..self.offsets[end]);
+                }
+            }
+
+            None
+        })
+    }
+}
+
+/// Find each occurrence of the folded pattern in the ASCII text, without folding
+/// it: an ASCII character is only folded to its lowercase, and is never the part of
+/// the folding of another character.
+///
+/// # Returns
+///
+/// Returns an [`Iterator`] that yields the byte ranges of all non-overlapping
+/// occurrences, in order, like [`Folded::find_iter()`]. The pattern must not be
+/// empty.
+pub fn find_ascii_iter<'a>(
+    text: &'a str,
+    pattern: &'a str,
+) -> impl Iterator<Item = Range<usize>> + 'a {
+    let mut from = 0;
+
+    iter::from_fn(move || {
+        let start = from
+            + text.as_bytes()[from..]
+                .windows(pattern.len())
+                .position(|window| window.eq_ignore_ascii_case(pattern.as_bytes()))?;
+        from = start + pattern.len();
+
+        Some(start..from)
+    })
+}
@@ -0,0 +1,259 @@
+use std::borrow::Cow;
+use std::cell::RefCell;
+use std::fmt::{self, Debug, Display, Formatter};
+use std::fs::{self, File};
+use std::io::{self, Read};
+use std::path::{Path, PathBuf};
+
+use super::errors::InvalidArgumentError;
+
+/// A file visited by [`Input::for_each_file()`]: its reader, or the error of the
+/// file or the directory that cannot be read.
+pub type Opened<'a> = Result<&'a mut dyn Read, InvalidArgumentError>;
+
+/// A path given to MiniGrep, or a reader given by a library.
+pub enum Input {
+    /// A file, or any path that is not a directory, checked and opened only during
+    /// the search.
+    File(PathBuf),
+    /// A directory, walked recursively during the search.
+    Directory(PathBuf),
+    /// The standard input, read when the path is [`Input::STDIN_PATH`] or when no
+    /// path is given.
+    Stdin,
+    /// Any reader, like a network buffer, a decompressed stream or a text in
+    /// memory. It is read once, by the first search.
+    Reader {
+        /// The name used to prefix lines found in the reader.
+        name: String,
+        /// The reader, read during the search.
+        reader: RefCell<Box<dyn Read>>,
+    },
+}
+
+impl Input {
+    /// The path given on CLI to read the standard input.
+    pub const STDIN_PATH: &'static str = "-";
+
+    /// The name used to prefix lines found in the standard input.
+    pub const STDIN_NAME: &'static str = "(standard input)";
+
+    /// Build an [`Input`] from a path given on CLI, that may not be valid Unicode.
+    /// The path is not checked, and a file is not opened before its search.
+    pub fn build(path: PathBuf) -> Input {
+        if path.as_os_str() == Self::STDIN_PATH {
+            Self::Stdin
+        } else if path.is_dir() {
+            Self::Directory(path)
+        } else {
+            Self::File(path)
+        }
+    }
+
+    /// Open the file given on CLI.
+    ///
+    /// # Returns
+    ///
+    /// Returns the opened file, or an [`InvalidArgumentError`] if the path does not
+    /// exist, is not a file, or points to a not readable file.
+    pub fn open(path: &Path) -> Result<File, InvalidArgumentError> {
+        if !path.is_file() {
+            if !path.exists() {
+                return Err(InvalidArgumentError::FileNotFound(path.to_path_buf()));
+            }
+
+            return match path.canonicalize() {
+                Ok(absolute_path) => Err(InvalidArgumentError::NotAFile(
+                    absolute_path,
+                    Self::type_name(path).to_owned(),
+                )),
+                Err(error) => Err(InvalidArgumentError::CannotResolvePath(
+                    path.to_path_buf(),
+                    error,
+                )),
+            };
+        }
+
+        File::open(path)
+            .map_err(|error| InvalidArgumentError::NotAReadableFile(path.to_path_buf(), error))
+    }
+
+    /// Get the name of the type of the node pointed by the path, like `socket` or
+    /// `fifo`, or `unknown` if its type cannot be read.
+    #[doc(hidden)]
+    fn type_name(path: &Path) -> &'static str {
+        let Ok(metadata) = fs::metadata(path) else {
+            return "unknown";
+        };
+        let file_type = metadata.file_type();
+
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::FileTypeExt;
+
+            if file_type.is_socket() {
+                return "socket";
+            } else if file_type.is_fifo() {
+                return "fifo";
+            } else if file_type.is_block_device() {
+                return "block device";
+            } else if file_type.is_char_device() {
+                return "character device";
+            }
+        }
+
+        if file_type.is_dir() {
+            "directory"
+        } else {
+            "unknown"
+        }
+    }
+
+    /// Build an [`Input`] from any reader, with the name used to prefix found lines.
+    pub fn reader(name: String, reader: impl Read + 'static) -> Input {
+        Self::Reader {
+            name,
+            reader: RefCell::new(Box::new(reader)),
+        }
+    }
+
+    /// Get the path given on CLI, or the name of the reader.
+    pub fn path(&self) -> &Path {
+        match self {
+            Self::File(path) | Self::Directory(path) => path,
+            Self::Stdin => Path::new(Self::STDIN_PATH),
+            Self::Reader { name, .. } => Path::new(name),
+        }
+    }
+
+    /// Call the visitor with each file of the input, and its path. The standard
+    /// input is visited as a file named [`Input::STDIN_NAME`], and a reader as a
+    /// file with its name.
+    ///
+    /// A directory is walked recursively, its entries are visited in the
+    /// lexicographic order, symbolic links to directories are not followed and
+    /// special files (sockets, pipes, ...) are skipped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first [`io::Error`] of the visitor, and stops the visit.
+    ///
+    /// # Read errors
+    ///
+    /// Call the visitor with an [`InvalidArgumentError`] in place of the reader if a
+    /// file cannot be opened or if a directory cannot be read, and continue the
+    /// walk.
+    pub fn for_each_file(
+        &self,
+        visitor: &mut impl FnMut(&Path, Opened<'_>) -> io::Result<()>,
+    ) -> io::Result<()> {
+        match self {
+            Self::File(path) => match Self::open(path) {
+                Ok(mut file) => visitor(path, Ok(&mut file)),
+                Err(error) => visitor(path, Err(error)),
+            },
+            Self::Directory(path) => Self::walk(path, visitor),
+            Self::Stdin => visitor(Path::new(Self::STDIN_NAME), Ok(&mut io::stdin().lock())),
+            Self::Reader { name, reader } => {
+                visitor(Path::new(name), Ok(reader.borrow_mut().as_mut()))
+            }
+        }
+    }
+
+    /// Walk recursively the directory, and call the visitor with each found file,
+    /// or with the error of each file or directory that cannot be read.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first [`io::Error`] of the visitor, and stops the walk.
+    #[doc(hidden)]
+    fn walk(
+        directory: &Path,
+        visitor: &mut impl FnMut(&Path, Opened<'_>) -> io::Result<()>,
+    ) -> io::Result<()> {
+        let not_readable =
+            |error| InvalidArgumentError::NotAReadableDirectory(directory.to_path_buf(), error);
+
+        let mut entries = Vec::new();
+        match fs::read_dir(directory) {
+            Ok(read_entries) => {
+                for entry in read_entries {
+                    match entry {
+                        Ok(entry) => entries.push(entry),
+                        Err(error) => visitor(directory, Err(not_readable(error)))?,
+                    }
+                }
+            }
+            Err(error) => return visitor(directory, Err(not_readable(error))),
+        }
+        entries.sort_by_key(|entry| entry.file_name());
+
+        for entry in entries {
+            let path = entry.path();
+            let is_directory = entry.file_type().is_ok_and(|file_type| file_type.is_dir());
+
+            if is_directory {
+                Self::walk(&path, visitor)?;
+                continue;
+            }
+
+            // Skip symbolic links to directories, sockets, pipes and other special
+            // files. A broken symbolic link is reported when opened.
+            if fs::metadata(&path).is_ok_and(|metadata| !metadata.is_file()) {
+                continue;
+            }
+
+            match File::open(&path) {
+                Ok(mut file) => visitor(&path, Ok(&mut file))?,
+                Err(error) => {
+                    let error = InvalidArgumentError::NotAReadableFile(path.clone(), error);
+                    visitor(&path, Err(error))?
+                }
+            }
+        }
+
+        Ok(())
+    }
+}
+
+impl Display for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => write!(f, "file '{}'", path.display()),
+            Self::Directory(path) => write!(f, "directory '{}'", path.display()),
+            Self::Stdin => write!(f, "standard input"),
+            Self::Reader { name, .. } => write!(f, "input '{name}'"),
+        }
+    }
+}
+
+impl Debug for Input {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::File(path) => f.debug_tuple("File").field(path).finish(),
+            Self::Directory(path) => f.debug_tuple("Directory").field(path).finish(),
+            Self::Stdin => f.write_str("Stdin"),
+            Self::Reader { name, .. } => f
+                .debug_struct("Reader")
+                .field("name", name)
+                .finish_non_exhaustive(),
+        }
+    }
+}
+
+/// Get the bytes of the path, to print it without loss even if it is not valid
+/// Unicode. On a platform other than Unix, the path is converted lossily.
+pub fn path_bytes(path: &Path) -> Cow<'_, [u8]> {
+    #[cfg(unix)]
+    {
+        use std::os::unix::ffi::OsStrExt;
+
+        Cow::Borrowed(path.as_os_str().as_bytes())
+    }
+
+    #[cfg(not(unix))]
+    match path.to_string_lossy() {
+        Cow::Borrowed(text) => Cow::Borrowed(text.as_bytes()),
+        Cow::Owned(text) => Cow::Owned(text.into_bytes()),
+    }
+}
@@ -0,0 +1,66 @@
+use std::fmt::Write;
+
+/// The alphabet of the standard base64 encoding.
+#[doc(hidden)]
+const BASE64_ALPHABET: &[u8; 64] =
+    b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+/// Encode the text as a JSON string, with its quotes.
+pub fn string(text: &str) -> String {
+    let mut encoded = String::with_capacity(text.len() + 2);
+    encoded.push('"');
+
+    for char in text.chars() {
+        match char {
+            '"' => encoded.push_str("\\\""),
+            '\\' => encoded.push_str("\\\\"),
+            '\n' => encoded.push_str("\\n"),
+            '\r' => encoded.push_str("\\r"),
+            '\t' => encoded.push_str("\\t"),
+            char if char.is_control() => {
+                let _ = write!(encoded, "\\u{:04x}", u32::from(char));
+            }
+            char => encoded.push(char),
+        }
+    }
+
+    encoded.push('"');
+    encoded
+}
+
+/// Encode arbitrary data as a JSON object, like `{"text":"..."}` if the data is
+/// valid UTF-8, else like `{"bytes":"..."}` with the data encoded in base64.
+///
+/// The encoded data is always valid JSON.
+pub fn data(bytes: &[u8]) -> String {
+    match std::str::from_utf8(bytes) {
+        Ok(text) => format!("{{\"text\":{}}}", string(text)),
+        Err(_) => format!("{{\"bytes\":\"{}\"}}", base64(bytes)),
+    }
+}
+
+/// Encode the bytes with the standard base64 encoding, with padding.
+#[doc(hidden)]
+fn base64(bytes: &[u8]) -> String {
+    let mut encoded = String::with_capacity(bytes.len().div_ceil(3) * 4);
+
+    for chunk in bytes.chunks(3) {
+        let group = chunk
+            .iter()
+            .enumerate()
+            .fold(0u32, |group, (index, &byte)| {
+                group | u32::from(byte) << (16 - 8 * index)
+            });
+
+        for index in 0..4 {
+            if index <= chunk.len() {
+                let sextet = (group >> (18 - 6 * index)) & 0x3f;
+                encoded.push(char::from(BASE64_ALPHABET[sextet as usize]));
+            } else {
+                encoded.push('=');
+            }
+        }
+    }
+
+    encoded
+}
@@ -0,0 +1,2953 @@
+//! MiniGrep, a simple grep, usable as a command or as a library.
+//!
+//! The [`Searcher`], built with a [`SearcherBuilder`], searches a pattern in each
+//! line of a reader, a string, a file or a directory, and yields a [`Match`] for
+//! each found line, or sends each event of the search to a [`Sink`]. The
+//! [`Command`] is the command-line interface built on it, printing the results
+//! with its sinks.
+//!
+//! # Examples
+//!
+//! Search in a string:
+//!
+//! ```rust
+//! use mini_grep::Searcher;
+//!
+//! let searcher = Searcher::builder("rust")
+//!     .case_sensitive(false)
+//!     .build()
+//!     .unwrap();
+//!
+//! let lines: Vec<_> = searcher
+//!     .search_str("Rust is fast.\nC is old.\nrustc compiles.\n")
+//!     .map(|found| (found.line_number, found.line().into_owned()))
+//!     .collect();
+//!
+//! assert_eq!(
+//!     lines,
+//!     [
+//!         (1, "Rust is fast.".to_owned()),
+//!         (3, "rustc compiles.".to_owned()),
+//!     ]
+//! );
+//! ```
+//!
+//! Run the command with the CLI arguments:
+//!
+//! ```rust,no_run
+//! use std::env::args_os;
+//! use std::process;
+//!
+//! use mini_grep::{ExitStatus, Parsed};
+//!
+//! let status = match Parsed::try_from(args_os()) {
+//!     Ok(Parsed::Run(command)) => command.execute(),
+//!     Ok(Parsed::Help(text) | Parsed::Version(text)) => {
+//!         println!("{text}");
+//!         ExitStatus::Found
+//!     }
+//!     Err(error) => {
+//!         eprintln!("error {}: {error}", error.code());
+//!         process::exit(error.exit_code());
+//!     }
+//! };
+//!
+//! process::exit(status.code());
+//! ```
+
+#![doc(issue_tracker_base_url = "https://github.com/Xyphenore/mini-grep/issues/")]
+
+pub use self::binary::BinaryPolicy;
+pub use self::color::ColorChoice;
+pub use self::command::{Command, ExitStatus, OutputFormat, Parsed, Report};
+pub use self::context::LineKind;
+pub use self::encoding::Encoding;
+pub use self::matcher::Matcher;
+pub use self::matching::{ColumnUnit, InvalidUtf8, Match, Submatch};
+pub use self::pattern::{LiteralMatcher, PatternSyntax, RegexMatcher};
+pub use self::searcher::{SearchedFile, Searcher, SearcherBuilder};
+pub use self::sink::Sink;
+
+/// The module contains the class [`Command`].
+mod command;
+
+/// The module contains the [`Searcher`] and its [`SearcherBuilder`].
+mod searcher;
+
+/// The module contains the [`Input`](input::Input) given to the class [`Command`].
+mod input;
+
+/// The module contains the [`Line`](line::Line) read from a file, with its number
+/// and its byte offset.
+mod line;
+
+/// The module contains the [`Match`] yielded by a search, with its [`Submatch`].
+mod matching;
+
+/// The module contains the [`Context`](context::Context) adapter, yielding context
+/// lines around selected lines.
+mod context;
+
+/// The module contains the parser of CLI [`options`].
+mod options;
+
+/// The module contains the [`Encoding`] of a file, and the
+/// [`Decoder`](encoding::Decoder) transcoding it to UTF-8.
+mod encoding;
+
+/// The module contains the [`BinaryPolicy`], and the detection of a binary file.
+mod binary;
+
+/// The module contains the [`Matcher`] trait, finding the pattern in each line.
+mod matcher;
+
+/// The module contains the full case [`folding`] of Unicode, used by the
+/// case-insensitive [`LiteralMatcher`].
+mod folding;
+
+/// The module contains the [`LiteralMatcher`] and the [`RegexMatcher`] of a
+/// pattern, according to its [`PatternSyntax`].
+mod pattern;
+
+/// The module contains the [`Sink`] trait, receiving the results of a search.
+mod sink;
+
+/// The module contains the [`Printer`](printer::Printer) of found lines, and the
+/// other printers of the output formats.
+mod printer;
+
+/// The module contains the [`Colors`](color::Colors) of the colored output.
+mod color;
+
+/// The module contains the encoding of the JSON output.
+mod json;
+
+/// All errors returned by the class [`Command`] and by the [`Searcher`].
+///
+/// # Errors
+///
+/// - [`MiniGrepArgsError`](errors::MiniGrepArgsError)
+/// - [`InvalidSyntaxError`](errors::InvalidSyntaxError)
+/// - [`InvalidArgumentError`](errors::InvalidArgumentError)
+/// - [`ReadError`](errors::ReadError)
+pub mod errors;
+
+#[cfg(test)]
+mod e2e_tests {
+    use std::process::Command as Cmd;
+
+    use rstest::*;
+    use rstest_reuse::{self, *};
+
+    use super::Command;
+
+    const EXAMPLE_FILE: &str = "resources/example.txt";
+    const TREE_DIR: &str = "resources/tree";
+    const BINARY_DIR: &str = "resources/binary";
+
+    #[fixture]
+    fn mini_grep_cmd(
+        #[default("pattern")] pattern: &'static str,
+        #[default(false)] give_ignore_case: bool,
+        #[default("0")] ignore_case: &'static str,
+        #[default(EXAMPLE_FILE)] filename: &'static str,
+    ) -> Cmd {
+        let mut cargo = Cmd::new("cargo");
+        cargo.args(["run", "--", "--banner", pattern, filename]);
+
+        if give_ignore_case {
+            cargo.env(Command::IGNORE_CASE_ENV_NAME, ignore_case);
+        }
+
+        cargo
+    }
+
+    fn mini_grep_cmd_with(args: &[&str], envs: &[(&str, &str)]) -> Cmd {
+        let mut cargo = Cmd::new("cargo");
+        cargo
+            .args(["run", "--"])
+            .args(args)
+            .env(Command::IGNORE_CASE_ENV_NAME, "0")
+            .envs(envs.iter().copied());
+
+        cargo
+    }
+
+    fn run(mut mini_grep_cmd: Cmd) -> (String, String, Option<i32>) {
+        let output = match mini_grep_cmd.output() {
+            Ok(output) => output,
+            Err(error) => {
+                panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+            }
+        };
+
+        let stdout = match String::from_utf8(output.stdout) {
+            Ok(stdout) => stdout,
+            Err(error) => {
+                panic!("Error during the string conversion of stdout. The error: '{error}'.")
+            }
+        };
+
+        let stderr = clear_useless_lines_from(match String::from_utf8(output.stderr) {
+            Ok(stderr) => stderr,
+            Err(error) => {
+                panic!("Error during the string conversion of stderr. The error: '{error}'.")
+            }
+        });
+
+        (stdout, stderr, output.status.code())
+    }
+
+    fn run_with_stdin(
+        mut mini_grep_cmd: Cmd,
+        input: impl AsRef<[u8]>,
+    ) -> (String, String, Option<i32>) {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        mini_grep_cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = mini_grep_cmd.spawn().unwrap_or_else(|error| {
+            panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+        });
+
+        if let Some(mut stdin) = child.stdin.take() {
+            stdin.write_all(input.as_ref()).unwrap_or_else(|error| {
+                panic!("Error during the write to stdin. The error: '{error}'.")
+            });
+        }
+
+        let output = child.wait_with_output().unwrap_or_else(|error| {
+            panic!("Error during the wait of the command mini-grep, the error: '{error}'.")
+        });
+
+        let stdout = String::from_utf8(output.stdout).unwrap_or_else(|error| {
+            panic!("Error during the string conversion of stdout. The error: '{error}'.")
+        });
+        let stderr =
+            clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(|error| {
+                panic!("Error during the string conversion of stderr. The error: '{error}'.")
+            }));
+
+        (stdout, stderr, output.status.code())
+    }
+
+    /// Run mini-grep with the line written to stdin again and again, like `yes`, until
+    /// mini-grep stops reading it. The run fails if mini-grep does not exit.
+    fn run_with_endless_stdin(
+        mut mini_grep_cmd: Cmd,
+        line: &'static [u8],
+    ) -> (String, Option<i32>) {
+        use std::io::Write;
+        use std::process::Stdio;
+        use std::sync::mpsc;
+        use std::thread;
+        use std::time::Duration;
+
+        mini_grep_cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = mini_grep_cmd.spawn().unwrap_or_else(|error| {
+            panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+        });
+        let mut stdin = child
+            .stdin
+            .take()
+            .unwrap_or_else(|| panic!("Missing stdin."));
+
+        thread::spawn(move || while stdin.write_all(line).is_ok() {});
+
+        let (sender, receiver) = mpsc::channel();
+        thread::spawn(move || {
+            let _ = sender.send(child.wait_with_output());
+        });
+
+        let output = receiver
+            .recv_timeout(Duration::from_secs(60))
+            .unwrap_or_else(|error| panic!("The search does not stop. The error: '{error}'."))
+            .unwrap_or_else(|error| {
+                panic!("Error during the wait of the command mini-grep, the error: '{error}'.")
+            });
+
+        (
+            String::from_utf8_lossy(&output.stdout).into_owned(),
+            output.status.code(),
+        )
+    }
+
+    fn clear_useless_lines_from(stderr: String) -> String {
+        String::from_iter(stderr.lines().filter(|line| {
+            let trimmed_line = line.trim_start();
+            !(trimmed_line.starts_with("Blocking")
+                || trimmed_line.starts_with("Running")
+                || trimmed_line.starts_with("Finished")
+                || trimmed_line.starts_with("Compiling"))
+        }))
+    }
+
+    #[template]
+    #[rstest]
+    #[case::case_sensitive("0", true)]
+    #[case::case_sensitive("false", true)]
+    #[case::case_sensitive("tata", true)]
+    #[case::case_sensitive("FALSE", true)]
+    #[case::case_sensitive("alpha", true)]
+    #[case::case_sensitive("", false)]
+    #[case::case_sensitive("  ", false)]
+    #[case::case_insensitive("1", true)]
+    #[case::case_insensitive("true", true)]
+    #[case::case_insensitive("TRUE", true)]
+    #[case::case_insensitive("TrUe", true)]
+    fn case_sensitive_test_cases(#[case] case_mode: &'static str, #[case] give_case_mode: bool) {}
+
+    mod with_an_invalid_pattern {
+        use super::*;
+
+        #[apply(case_sensitive_test_cases)]
+        fn as_an_empty_pattern(
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with("", _give_case_mode, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = match String::from_utf8(output.stderr) {
+                Ok(stderr) => stderr,
+                Err(error) => {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                }
+            };
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(!stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            assert!(
+                stderr.contains("Cannot have a blank searched text ''."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[apply(case_sensitive_test_cases)]
+        fn as_a_blank_pattern(
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with("  ", _give_case_mode, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = match String::from_utf8(output.stderr) {
+                Ok(stderr) => stderr,
+                Err(error) => {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                }
+            };
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(!stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            assert!(
+                stderr.contains("Cannot have a blank searched text '  '."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_an_invalid_path {
+        use super::*;
+
+        const PATTERN: &str = "pattern";
+
+        #[cfg(unix)]
+        #[fixture]
+        fn create_socket() -> &'static str {
+            use std::fs::create_dir;
+            use std::os::unix::net::UnixListener;
+            use std::path::Path;
+
+            let tmp_dir = Path::new("tmp");
+            let tmp_dir_string = tmp_dir.to_str().unwrap_or_else(|| {
+                panic!("Cannot convert the tmp directory to its string representation.")
+            });
+
+            if !tmp_dir.exists() {
+                create_dir(tmp_dir).unwrap_or_else(|error| panic!(
+                    "Error during the creation of the directory '{tmp_dir_string}'. The error: '{error}'."
+                ));
+            }
+
+            let file = tmp_dir.join("socket_test_mini_grep.sock");
+            let file_string = file.to_str().unwrap_or_else(|| {
+                panic!("Cannot convert the socket path to its string representation.")
+            });
+
+            if !file.exists() {
+                let _ = UnixListener::bind(file.clone()).unwrap_or_else(|error| {
+                    panic!(
+                        "Impossible to create an Unix socket '{file_string}'. The error: '{error}'."
+                    )
+                });
+            }
+
+            "tmp/socket_test_mini_grep.sock"
+        }
+
+        #[cfg(unix)]
+        #[apply(case_sensitive_test_cases)]
+        fn pointing_to_unknown_typed_node(
+            #[from(create_socket)] _create_socket: &'static str,
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with(PATTERN, _give_case_mode, _case_mode, _create_socket)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(match String::from_utf8(output.stderr) {
+                Ok(stderr) => stderr,
+                Err(error) => {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                }
+            });
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(!stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            assert!(
+                stderr.contains("is not a file, it is a socket."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn pointing_to_a_device() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(&[PATTERN, "/dev/null"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains("error 131: '/dev/null' is not a file, it is a character device."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[apply(case_sensitive_test_cases)]
+        fn pointing_to_a_not_existing_file(
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with(PATTERN, _give_case_mode, _case_mode, "resources/not_existing_file")]
+            mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = match String::from_utf8(output.stderr) {
+                Ok(stderr) => stderr,
+                Err(error) => {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                }
+            };
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(!stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            assert!(
+                stderr.contains("The file 'resources/not_existing_file' does not exist."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[cfg(unix)]
+        #[fixture]
+        fn not_readable_file() -> &'static str {
+            use std::fs::File;
+            use std::path::Path;
+            use std::fs::set_permissions;
+            use std::os::unix::fs::PermissionsExt;
+
+            let not_readable_file = "resources/not_readable_file.txt";
+
+            let not_readable_file_path = Path::new(not_readable_file);
+            if !not_readable_file_path.exists() {
+                if let Err(error) = File::create_new(not_readable_file_path) {
+                    panic!(
+                        "Cannot create the not-readable file '{not_readable_file}'. The \
+                        error: '{error}'.",
+                    );
+                }
+            }
+
+            let mut perms = not_readable_file_path.metadata()
+                .unwrap_or_else(|error| panic!(
+                    "Error during the get of permissions of '{not_readable_file}'. The error: '{error}'."
+                ))
+                .permissions();
+
+            // Remove read and write rights for the user
+            perms.set_mode(0o044);
+
+            if let Err(error) = set_permissions(not_readable_file, perms) {
+                panic!(
+                    "Cannot make the file '{not_readable_file}' not-readable. The error: '{error}'."
+                );
+            }
+
+            not_readable_file
+        }
+
+        #[cfg(unix)]
+        #[apply(case_sensitive_test_cases)]
+        fn pointing_to_a_file_without_read_permission(
+            not_readable_file: &'static str,
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with(PATTERN, _give_case_mode, _case_mode, not_readable_file)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(match String::from_utf8(output.stderr) {
+                Ok(stderr) => stderr,
+                Err(error) => {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                }
+            });
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(!stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            assert!(
+                stderr.contains(&format!(
+                    "Cannot open the file '{not_readable_file}', due to this error",
+                )),
+                "Bad error in stderr: '{stderr}'.",
+            );
+
+            assert!(
+                stderr.contains("(os error 13)"),
+                "Bad error in stderr: '{stderr}'",
+            );
+        }
+    }
+
+    mod with_a_valid_file {
+        use super::*;
+
+        #[apply(case_sensitive_test_cases)]
+        fn without_the_pattern(
+            #[case] _case_mode: &'static str,
+            #[case] give_case_mode: bool,
+            #[with("pattern", give_case_mode, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(
+                |error| {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                },
+            ));
+
+            assert!(!stdout.is_empty(), "Standard output: '{stdout}'.");
+
+            if give_case_mode {
+                assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            } else {
+                assert_eq!(
+                    stderr,
+                    "Error during the get of the variable 'IGNORE_CASE'. The error: \
+                    'environment variable not found'.",
+                    "Bad error when getting the environment variable {}.",
+                    Command::IGNORE_CASE_ENV_NAME,
+                );
+            }
+
+            let lines: Vec<_> = stdout.lines().collect();
+
+            let first_line = match lines.first() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with(&format!(
+                    "The file '{EXAMPLE_FILE}' does not contain any line with the case ",
+                )),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert!(
+                first_line.ends_with("pattern 'pattern'."),
+                "The first line: '{first_line}' finishes with bad words.",
+            );
+
+            if let Some(line) = lines.get(1) {
+                panic!("A second line exists in stdout: '{line}'.")
+            }
+        }
+
+        #[rstest]
+        #[case::case_sensitive("0", true)]
+        #[case::case_sensitive("false", true)]
+        #[case::case_sensitive("tata", true)]
+        #[case::case_sensitive("FALSE", true)]
+        #[case::case_sensitive("alpha", true)]
+        #[case::case_sensitive("", false)]
+        #[case::case_sensitive("  ", false)]
+        fn with_a_line_with_the_pattern(
+            #[case] _case_mode: &'static str,
+            #[case] give_case_mode: bool,
+            #[with("test_data", give_case_mode, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(
+                |error| {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                },
+            ));
+
+            assert!(!stdout.is_empty(), "Standard output: '{stdout}'.");
+
+            if give_case_mode {
+                assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            } else {
+                assert_eq!(
+                    stderr,
+                    "Error during the get of the variable 'IGNORE_CASE'. The error: \
+                    'environment variable not found'.",
+                    "Bad error when getting the environment variable {}.",
+                    Command::IGNORE_CASE_ENV_NAME,
+                );
+            }
+
+            let lines: Vec<_> = stdout.lines().collect();
+
+            let first_line = match lines.first() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with(&format!(
+                    "The file '{EXAMPLE_FILE}' contains these lines with the case",
+                )),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert!(
+                first_line.ends_with("pattern 'test_data':"),
+                "The first line: '{first_line}' finishes with bad words.",
+            );
+
+            let second_line = match lines.get(1) {
+                Some(line) => line,
+                None => panic!("Missing the second line in stdout: '{stdout}'."),
+            };
+            assert_eq!(
+                second_line.to_string(),
+                "1: # test_data.txt",
+                "The found line is invalid for the file '{EXAMPLE_FILE}'."
+            );
+
+            if let Some(line) = lines.get(2) {
+                panic!("A third line exists in stdout: '{line}'.")
+            }
+        }
+
+        #[rstest]
+        #[case::case_insensitive("1")]
+        #[case::case_insensitive("true")]
+        #[case::case_insensitive("TRUE")]
+        #[case::case_insensitive("TrUe")]
+        fn with_a_line_with_the_pattern_and_ignore_case(
+            #[case] _case_mode: &'static str,
+            #[with("TesT_DaTA", true, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(
+                |error| {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                },
+            ));
+
+            assert!(!stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+
+            let first_line = match lines.first() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with(&format!(
+                    "The file '{EXAMPLE_FILE}' contains these lines with the case",
+                )),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert!(
+                first_line.ends_with("pattern 'TesT_DaTA':"),
+                "The first line: '{first_line}' finishes with bad words.",
+            );
+
+            let second_line = match lines.get(1) {
+                Some(line) => line,
+                None => panic!("Missing the second line in stdout: '{stdout}'."),
+            };
+            assert_eq!(
+                second_line.to_string(),
+                "1: # test_data.txt".to_owned(),
+                "The found line is invalid for the file '{EXAMPLE_FILE}'."
+            );
+
+            if let Some(line) = lines.get(2) {
+                panic!("A third line exists in stdout: '{line}'.")
+            }
+        }
+
+        #[rstest]
+        #[case::case_sensitive("0", true)]
+        #[case::case_sensitive("false", true)]
+        #[case::case_sensitive("tata", true)]
+        #[case::case_sensitive("FALSE", true)]
+        #[case::case_sensitive("alpha", true)]
+        #[case::case_sensitive("", false)]
+        #[case::case_sensitive("  ", false)]
+        fn with_many_line_with_the_pattern(
+            #[case] _case_mode: &'static str,
+            #[case] give_case_mode: bool,
+            #[with("Rust", give_case_mode, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(
+                |error| {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                },
+            ));
+
+            assert!(!stdout.is_empty(), "Standard output: '{stdout}'.");
+
+            if give_case_mode {
+                assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            } else {
+                assert_eq!(
+                    stderr,
+                    "Error during the get of the variable 'IGNORE_CASE'. The error: \
+                    'environment variable not found'.",
+                    "Bad error when getting the environment variable {}.",
+                    Command::IGNORE_CASE_ENV_NAME,
+                );
+            }
+
+            let mut lines: Vec<_> = stdout.lines().collect();
+
+            let first_line = match lines.first() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with(&format!(
+                    "The file '{EXAMPLE_FILE}' contains these lines with the case",
+                )),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert!(
+                first_line.ends_with("pattern 'Rust':"),
+                "The first line: '{first_line}' finishes with bad words.",
+            );
+
+            // Drop the first line
+            lines.remove(0);
+
+            let waited_lines = [
+                "3: This is a Rust Rover file.",
+                "4: RustRover is a very good tool built in Rust.",
+                "5: If you like Rust, you'd love this tool.",
+                "6: RustRover 2024.1.4 is the best version so far.",
+                "7: Programming is fun especially with a tool like RustRover.",
+            ];
+
+            check_many_lines_are_good(lines.into_iter(), waited_lines.into_iter(), "Rust")
+        }
+
+        fn check_many_lines_are_good<'a, I, J>(lines: I, mut waited_lines: J, pattern: &str)
+        where
+            I: Iterator<Item = &'a str>,
+            J: Iterator<Item = &'a str>,
+        {
+            for found_line in lines {
+                let line = match waited_lines.next() {
+                    Some(entry) => entry,
+                    None => panic!("MiniGrep found too many lines for the pattern '{pattern}'."),
+                };
+
+                assert_eq!(
+                    found_line.to_string(),
+                    line,
+                    "The found line is invalid for the file '{EXAMPLE_FILE}'. \
+                    Waited line: '{line}'",
+                );
+            }
+
+            if let Some(line) = waited_lines.next() {
+                panic!("MiniGrep found no enough lines for the pattern '{pattern}': '{line}'.")
+            }
+        }
+
+        #[rstest]
+        #[case::case_insensitive("1")]
+        #[case::case_insensitive("true")]
+        #[case::case_insensitive("TRUE")]
+        #[case::case_insensitive("TrUe")]
+        fn with_many_line_with_the_pattern_and_ignore_case(
+            #[case] _case_mode: &'static str,
+            #[with("rUsT", true, _case_mode)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let stderr = clear_useless_lines_from(String::from_utf8(output.stderr).unwrap_or_else(
+                |error| {
+                    panic!("Error during the string conversion of stderr. The error: '{error}'.")
+                },
+            ));
+
+            assert!(!stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let mut lines: Vec<_> = stdout.lines().collect();
+
+            let first_line = match lines.first() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with(&format!(
+                    "The file '{EXAMPLE_FILE}' contains these lines with the case",
+                )),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert!(
+                first_line.ends_with("pattern 'rUsT':"),
+                "The first line: '{first_line}' finishes with bad words.",
+            );
+
+            // Drop the first line
+            lines.remove(0);
+
+            let waited_lines = [
+                "3: This is a Rust Rover file.",
+                "4: RustRover is a very good tool built in Rust.",
+                "5: If you like Rust, you'd love this tool.",
+                "6: RustRover 2024.1.4 is the best version so far.",
+                "7: Programming is fun especially with a tool like RustRover.",
+            ];
+
+            check_many_lines_are_good(lines.into_iter(), waited_lines.into_iter(), "rUsT")
+        }
+    }
+
+    mod with_a_regex_pattern {
+        use super::*;
+
+        #[test]
+        fn with_anchors_and_classes() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &[r"^Rust\w+ (is|2\d{3})", EXAMPLE_FILE],
+                &[(Command::REGEX_ENV_NAME, "1")],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "4:RustRover is a very good tool built in Rust.",
+                    "6:RustRover 2024.1.4 is the best version so far.",
+                ],
+                "The found lines are invalid for the file '{EXAMPLE_FILE}'.",
+            );
+        }
+
+        #[test]
+        fn with_ignore_case() {
+            let (stdout, stderr, _) = run(mini_grep_cmd_with(
+                &["^programming.+RUSTROVER\\.$", EXAMPLE_FILE],
+                &[
+                    (Command::REGEX_ENV_NAME, "true"),
+                    (Command::IGNORE_CASE_ENV_NAME, "1"),
+                ],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                ["7:Programming is fun especially with a tool like RustRover."],
+                "The found lines are invalid for the file '{EXAMPLE_FILE}'.",
+            );
+        }
+
+        #[test]
+        fn without_the_regex_mode() {
+            let (stdout, _, code) = run(mini_grep_cmd_with(&["^Rust", EXAMPLE_FILE], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(1), "Bad exit code.");
+        }
+
+        #[test]
+        fn as_an_invalid_regex() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["Rust(Rover", EXAMPLE_FILE],
+                &[(Command::REGEX_ENV_NAME, "1")],
+            ));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+
+            assert!(
+                stderr.contains(
+                    "error 135: The pattern 'Rust(Rover' is not a valid regular expression"
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+            assert!(
+                stderr.contains("    ^"),
+                "The error does not point at the offending position: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_many_paths {
+        use super::*;
+
+        #[apply(case_sensitive_test_cases)]
+        fn pointing_to_a_directory(
+            #[case] _case_mode: &'static str,
+            #[case] _give_case_mode: bool,
+            #[with("Rust", _give_case_mode, _case_mode, TREE_DIR)] mut mini_grep_cmd: Cmd,
+        ) {
+            let output = match mini_grep_cmd.output() {
+                Ok(output) => output,
+                Err(error) => {
+                    panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+                }
+            };
+
+            let stdout = match String::from_utf8(output.stdout) {
+                Ok(stdout) => stdout,
+                Err(error) => {
+                    panic!("Error during the string conversion of stdout. The error: '{error}'.")
+                }
+            };
+
+            let mut lines = stdout.lines();
+
+            let first_line = match lines.next() {
+                Some(line) => line,
+                None => panic!("Missing the first line in stdout: '{stdout}'."),
+            };
+
+            assert!(
+                first_line.starts_with("The searched files contain these lines with the case"),
+                "The first line: '{first_line}' is invalid.",
+            );
+            assert_eq!(
+                lines.collect::<Vec<_>>(),
+                [
+                    "resources/tree/languages.txt:1: Rust is a programming language.",
+                    "resources/tree/nested/tools.txt:1: Cargo builds Rust crates.",
+                ],
+                "The found lines are invalid for the directory '{TREE_DIR}'.",
+            );
+        }
+
+        #[test]
+        fn pointing_to_many_files() {
+            let (stdout, stderr, _) = run(mini_grep_cmd_with(
+                &["programming", EXAMPLE_FILE, "resources/tree/languages.txt"],
+                &[],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "resources/tree/languages.txt:1:Rust is a programming language.",
+                    "resources/tree/languages.txt:2:C is a programming language.",
+                ],
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_not_existing_file() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &[
+                    "programming",
+                    "resources/not_existing_file",
+                    "resources/tree/languages.txt",
+                ],
+                &[],
+            ));
+
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr
+                    .contains("error 132: The file 'resources/not_existing_file' does not exist."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+            assert_eq!(stdout.lines().count(), 2, "Standard output: '{stdout}'.");
+        }
+
+        #[test]
+        fn without_the_pattern() {
+            let (stdout, _, code) =
+                run(mini_grep_cmd_with(&["Python", TREE_DIR, EXAMPLE_FILE], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(1), "Bad exit code.");
+        }
+
+        #[test]
+        fn with_a_banner() {
+            let (stdout, _, code) = run(mini_grep_cmd_with(
+                &["--banner", "Python", TREE_DIR, EXAMPLE_FILE],
+                &[],
+            ));
+
+            assert_eq!(code, Some(1), "Bad exit code.");
+            assert_eq!(
+                stdout.trim_end(),
+                "The searched files do not contain any line with the case sensitive \
+                pattern 'Python'.",
+                "Standard output: '{stdout}'.",
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn with_a_not_readable_file_in_a_directory() {
+            use std::fs::{create_dir_all, write};
+            use std::os::unix::fs::symlink;
+
+            let directory = std::env::temp_dir().join("mini_grep_walk_with_a_broken_link");
+            let _ = std::fs::remove_dir_all(&directory);
+            create_dir_all(&directory).unwrap_or_else(|error| {
+                panic!("Cannot create the directory '{directory:?}'. The error: '{error}'.")
+            });
+            write(directory.join("a_broken_link_before.txt"), "Rust\n").unwrap_or_else(|error| {
+                panic!("Cannot create a file in '{directory:?}'. The error: '{error}'.")
+            });
+            symlink(directory.join("missing"), directory.join("b_broken_link")).unwrap_or_else(
+                |error| panic!("Cannot create a link in '{directory:?}'. The error: '{error}'."),
+            );
+            write(directory.join("c_broken_link_after.txt"), "Rust\n").unwrap_or_else(|error| {
+                panic!("Cannot create a file in '{directory:?}'. The error: '{error}'.")
+            });
+
+            let directory = directory.to_str().unwrap_or_else(|| {
+                panic!("Cannot convert the temporary directory to its string representation.")
+            });
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(&["Rust", directory], &[]));
+
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(&format!("Cannot open the file '{directory}/b_broken_link'")),
+                "Bad error in stderr: '{stderr}'.",
+            );
+            assert_eq!(stdout.lines().count(), 2, "Standard output: '{stdout}'.");
+        }
+    }
+
+    mod with_the_standard_input {
+        use super::*;
+
+        const INPUT: &str = "error: first\ninfo: second\nerror: third\n";
+
+        #[rstest]
+        #[case::without_path(&["error"])]
+        #[case::with_a_dash(&["error", "-"])]
+        fn as_the_only_input(#[case] args: &[&str]) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                ["1:error: first", "3:error: third"],
+                "The found lines are invalid for the standard input.",
+            );
+        }
+
+        #[test]
+        fn with_a_file() {
+            let (stdout, stderr, _) = run_with_stdin(
+                mini_grep_cmd_with(&["Rust,", "-", EXAMPLE_FILE], &[]),
+                "Rust, fast\n",
+            );
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "(standard input):1:Rust, fast",
+                    "resources/example.txt:5:If you like Rust, you'd love this tool.",
+                ],
+                "The found lines are invalid.",
+            );
+        }
+    }
+
+    mod with_options {
+        use super::*;
+
+        #[rstest]
+        #[case::short("-h")]
+        #[case::long("--help")]
+        fn to_print_the_help(#[case] option: &str) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(&[option], &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            for expected in [
+                "Usage:",
+                "-i, --ignore-case",
+                "-e, --regexp=PATTERN",
+                "REGEX",
+            ] {
+                assert!(
+                    stdout.contains(expected),
+                    "Missing '{expected}' in '{stdout}'."
+                );
+            }
+        }
+
+        #[rstest]
+        #[case::short("-V")]
+        #[case::long("--version")]
+        fn to_print_the_version(#[case] option: &str) {
+            let (stdout, _, code) = run(mini_grep_cmd_with(&[option, "pattern"], &[]));
+
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.trim_end(),
+                format!("mini-grep {}", env!("CARGO_PKG_VERSION")),
+                "Bad version.",
+            );
+        }
+
+        #[rstest]
+        #[case::separated(&["-i", "-E", "^rust", EXAMPLE_FILE])]
+        #[case::combined(&["-iE", "^rust", EXAMPLE_FILE])]
+        #[case::long(&["--ignore-case", "--extended-regexp", "^rust", EXAMPLE_FILE])]
+        #[case::after_the_path(&["^rust", EXAMPLE_FILE, "-iE"])]
+        #[case::glued_value(&["-iEe^rust", EXAMPLE_FILE])]
+        #[case::long_value(&["-iE", "--regexp=^rust", EXAMPLE_FILE])]
+        #[case::separated_long_value(&["-iE", "--regexp", "^rust", EXAMPLE_FILE])]
+        fn with_flags(#[case] args: &[&str]) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                [
+                    "4:RustRover is a very good tool built in Rust.",
+                    "6:RustRover 2024.1.4 is the best version so far.",
+                ],
+                "The found lines are invalid for the file '{EXAMPLE_FILE}'.",
+            );
+        }
+
+        #[rstest]
+        #[case::after_double_dash(&["--", "-v"])]
+        #[case::with_regexp(&["-e", "-v"])]
+        fn with_a_pattern_starting_with_a_dash(#[case] args: &[&str]) {
+            let (stdout, stderr, _) =
+                run_with_stdin(mini_grep_cmd_with(args, &[]), "with -v\nwithout\n");
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(lines, ["1:with -v"], "The found lines are invalid.");
+        }
+
+        #[rstest]
+        #[case::bare(&["--color", "RustRover is"])]
+        #[case::bare_after_a_flag(&["-i", "--color", "rustrover is"])]
+        #[case::separated_value(&["--color", "never", "RustRover is"])]
+        fn with_a_color_before_the_pattern(#[case] args: &[&str]) {
+            let mut args = args.to_vec();
+            args.push(EXAMPLE_FILE);
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(&args, &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                ["4:RustRover is a very good tool built in Rust."],
+                "The found lines are invalid for the file '{EXAMPLE_FILE}'.",
+            );
+        }
+
+        #[rstest]
+        #[case::short(&["-e", "Rust", "-e", "C", EXAMPLE_FILE])]
+        #[case::long(&["--regexp=Rust", "-eC", EXAMPLE_FILE])]
+        fn with_a_repeated_pattern(#[case] args: &[&str]) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains("error 138: The option '--regexp' is given many times."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[rstest]
+        #[case::misspelled_long(
+            "--ignor-case",
+            127,
+            "Unknown option '--ignor-case'. Did you mean '--ignore-case'?"
+        )]
+        #[case::unknown_long("--foo", 127, "Unknown option '--foo'. Call")]
+        #[case::unknown_short("-iz", 127, "Unknown option '-z'.")]
+        #[case::missing_value("-e", 128, "The option '-e' requires a value.")]
+        #[case::unexpected_value(
+            "--ignore-case=yes",
+            129,
+            "The option '--ignore-case' does not take a value."
+        )]
+        fn with_an_invalid_option(
+            #[case] option: &str,
+            #[case] expected_code: i32,
+            #[case] expected_error: &str,
+        ) {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["pattern", EXAMPLE_FILE, option], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(&format!("error {expected_code}: {expected_error}")),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_a_stream {
+        use std::io::{BufRead, BufReader, Write};
+        use std::process::Stdio;
+        use std::sync::mpsc;
+        use std::thread;
+        use std::time::Duration;
+
+        use super::*;
+
+        #[test]
+        fn prints_each_found_line_before_the_end_of_the_input() {
+            let mut mini_grep_cmd = mini_grep_cmd_with(&["error"], &[]);
+            mini_grep_cmd.stdin(Stdio::piped()).stdout(Stdio::piped());
+
+            let mut child = mini_grep_cmd.spawn().unwrap_or_else(|error| {
+                panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+            });
+
+            let mut stdin = child
+                .stdin
+                .take()
+                .unwrap_or_else(|| panic!("Missing stdin."));
+            let stdout = child
+                .stdout
+                .take()
+                .unwrap_or_else(|| panic!("Missing stdout."));
+
+            stdin
+                .write_all(b"info: first\nerror: second\n")
+                .unwrap_or_else(|error| {
+                    panic!("Error during the write to stdin. The error: '{error}'.")
+                });
+            stdin.flush().unwrap_or_else(|error| {
+                panic!("Error during the flush of stdin. The error: '{error}'.")
+            });
+
+            let (sender, receiver) = mpsc::channel();
+            thread::spawn(move || {
+                let lines: Vec<_> = BufReader::new(stdout).lines().take(1).collect();
+                let _ = sender.send(lines);
+            });
+
+            // The standard input is still open, the found line must be already printed.
+            let lines = receiver
+                .recv_timeout(Duration::from_secs(60))
+                .unwrap_or_else(|error| panic!("No found line printed. The error: '{error}'."));
+
+            drop(stdin);
+            let _ = child.wait();
+
+            let found_line = match lines.first() {
+                Some(Ok(line)) => line,
+                _ => panic!("Missing the found line in stdout: '{lines:?}'."),
+            };
+            assert_eq!(found_line, "2:error: second", "The found line is invalid.");
+        }
+    }
+
+    mod with_an_exit_status {
+        use super::*;
+
+        #[rstest]
+        #[case::found(&["Rust", EXAMPLE_FILE], 0)]
+        #[case::not_found(&["Python", EXAMPLE_FILE], 1)]
+        #[case::found_with_a_banner(&["--banner", "Rust", EXAMPLE_FILE], 0)]
+        #[case::not_found_with_a_banner(&["--banner", "Python", EXAMPLE_FILE], 1)]
+        #[case::not_existing_file(&["Rust", "resources/not_existing_file"], 2)]
+        #[case::blank_pattern(&[" ", EXAMPLE_FILE], 2)]
+        #[case::unknown_option(&["--foo", "Rust", EXAMPLE_FILE], 2)]
+        fn follows_the_grep_convention(#[case] args: &[&str], #[case] expected_code: i32) {
+            let (_, _, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert_eq!(code, Some(expected_code), "Bad exit code.");
+        }
+
+        #[test]
+        fn with_the_error_code_in_stderr() {
+            let (_, stderr, _) = run(mini_grep_cmd_with(
+                &["Rust", "resources/not_existing_file"],
+                &[],
+            ));
+
+            assert!(
+                stderr
+                    .contains("error 132: The file 'resources/not_existing_file' does not exist."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_an_inverted_match {
+        use super::*;
+
+        #[rstest]
+        #[case::literal(&["-v", "Rust", EXAMPLE_FILE])]
+        #[case::long(&["--invert-match", "Rust", EXAMPLE_FILE])]
+        #[case::regex(&["-vE", "R[a-z]+t", EXAMPLE_FILE])]
+        #[case::ignore_case(&["-vi", "rUST", EXAMPLE_FILE])]
+        fn prints_lines_without_the_pattern(#[case] args: &[&str]) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+
+            let lines: Vec<_> = stdout.lines().collect();
+            assert_eq!(
+                lines,
+                ["1:# test_data.txt", "2:"],
+                "The found lines are invalid for the file '{EXAMPLE_FILE}'.",
+            );
+        }
+
+        #[test]
+        fn without_any_line() {
+            let (stdout, _, code) = run(mini_grep_cmd_with(&["-vE", ".*", EXAMPLE_FILE], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(1), "Bad exit code.");
+        }
+
+        #[test]
+        fn with_a_banner() {
+            let (stdout, _, _) = run(mini_grep_cmd_with(
+                &["--banner", "-v", "Rust", EXAMPLE_FILE],
+                &[],
+            ));
+
+            let first_line = stdout.lines().next().unwrap_or_default();
+            assert_eq!(
+                first_line,
+                format!(
+                    "The file '{EXAMPLE_FILE}' contains these lines without the case \
+                    sensitive pattern 'Rust':"
+                ),
+                "The first line: '{first_line}' is invalid.",
+            );
+        }
+    }
+
+    mod with_context_lines {
+        use super::*;
+
+        const INPUT: &str = "a\nb\nmatch 1\nc\nd\ne\nf\ng\nmatch 2\nmatch 3\nh\n";
+
+        #[rstest]
+        #[case::context(&["-C", "1", "match"], &["2-b", "3:match 1", "4-c", "--", "8-g", "9:match 2", "10:match 3", "11-h"])]
+        #[case::merged_context(&["-C2", "match"], &["1-a", "2-b", "3:match 1", "4-c", "5-d", "--", "7-f", "8-g", "9:match 2", "10:match 3", "11-h"])]
+        #[case::overlapping_context(&["--context=3", "match"], &["1-a", "2-b", "3:match 1", "4-c", "5-d", "6-e", "7-f", "8-g", "9:match 2", "10:match 3", "11-h"])]
+        #[case::before(&["-B1", "match"], &["2-b", "3:match 1", "--", "8-g", "9:match 2", "10:match 3"])]
+        #[case::after(&["--after-context", "1", "match"], &["3:match 1", "4-c", "--", "9:match 2", "10:match 3", "11-h"])]
+        #[case::after_overriding_context(&["-A0", "-C1", "match"], &["2-b", "3:match 1", "--", "8-g", "9:match 2", "10:match 3"])]
+        #[case::inverted(&["-v", "-A1", "[a-z]$", "-E"], &["3:match 1", "4-c", "--", "9:match 2", "10:match 3", "11-h"])]
+        fn around_selected_lines(#[case] args: &[&str], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_many_files() {
+            let (stdout, stderr, _) = run(mini_grep_cmd_with(
+                &["-B1", "C is", TREE_DIR, EXAMPLE_FILE],
+                &[],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                [
+                    "resources/tree/languages.txt-1-Rust is a programming language.",
+                    "resources/tree/languages.txt:2:C is a programming language.",
+                ],
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_number() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(&["-C", "two", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains("error 136: The value 'two' of the option '--context' is invalid."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_a_summary {
+        use super::*;
+
+        #[rstest]
+        #[case::count(&["-c", "Rust", TREE_DIR], &["resources/tree/languages.txt:1", "resources/tree/nested/tools.txt:1"], Some(0))]
+        #[case::count_of_a_file(&["--count", "Rust", EXAMPLE_FILE], &["5"], Some(0))]
+        #[case::count_without_match(&["-c", "Python", TREE_DIR], &["resources/tree/languages.txt:0", "resources/tree/nested/tools.txt:0"], Some(1))]
+        #[case::inverted_count(&["-vc", "Rust", TREE_DIR], &["resources/tree/languages.txt:1", "resources/tree/nested/tools.txt:1"], Some(0))]
+        #[case::files_with_matches(&["-l", "C", TREE_DIR], &["resources/tree/languages.txt", "resources/tree/nested/tools.txt"], Some(0))]
+        #[case::files_with_matches_of_a_file(&["--files-with-matches", "Rust", EXAMPLE_FILE], &[EXAMPLE_FILE], Some(0))]
+        #[case::files_without_match(&["-L", "Cargo", TREE_DIR], &["resources/tree/languages.txt"], Some(0))]
+        #[case::files_without_match_none(&["--files-without-match", "a", TREE_DIR], &[], Some(1))]
+        #[case::banner_ignored(&["--banner", "-l", "Cargo", TREE_DIR], &["resources/tree/nested/tools.txt"], Some(0))]
+        fn of_each_file(
+            #[case] args: &[&str],
+            #[case] expected_lines: &[&str],
+            #[case] expected_code: Option<i32>,
+        ) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, expected_code, "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The summary is invalid.",
+            );
+        }
+
+        #[rstest]
+        #[case::count(&["-c", "match"], "2\n")]
+        #[case::files_with_matches(&["-l", "match"], "(standard input)\n")]
+        #[case::files_without_match(&["-L", "other"], "(standard input)\n")]
+        fn of_the_standard_input(#[case] args: &[&str], #[case] expected_stdout: &str) {
+            let (stdout, stderr, code) =
+                run_with_stdin(mini_grep_cmd_with(args, &[]), "match\nno\nmatch\n");
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(stdout, expected_stdout, "The summary is invalid.");
+        }
+
+        #[rstest]
+        #[case::files_with_matches(&["-l", "foo"], "(standard input)\n", Some(0))]
+        #[case::files_without_match(&["-L", "foo"], "", Some(1))]
+        fn of_an_endless_standard_input(
+            #[case] args: &[&str],
+            #[case] expected_stdout: &str,
+            #[case] expected_code: Option<i32>,
+        ) {
+            let (stdout, code) = run_with_endless_stdin(mini_grep_cmd_with(args, &[]), b"foo\n");
+
+            assert_eq!(code, expected_code, "Bad exit code.");
+            assert_eq!(stdout, expected_stdout, "The summary is invalid.");
+        }
+    }
+
+    mod with_colors {
+        use super::*;
+
+        #[rstest]
+        #[case::always(&["--color=always", "Rust", TREE_DIR], &[], &[
+            "\x1b[35mresources/tree/languages.txt\x1b[m\x1b[36m:\x1b[m\x1b[32m1\x1b[m\x1b[36m:\x1b[m\x1b[01;31mRust\x1b[m is a programming language.",
+            "\x1b[35mresources/tree/nested/tools.txt\x1b[m\x1b[36m:\x1b[m\x1b[32m1\x1b[m\x1b[36m:\x1b[mCargo builds \x1b[01;31mRust\x1b[m crates.",
+        ])]
+        #[case::always_despite_no_color(&["--color", "always", "C is", TREE_DIR], &[("NO_COLOR", "1")], &[
+            "\x1b[35mresources/tree/languages.txt\x1b[m\x1b[36m:\x1b[m\x1b[32m2\x1b[m\x1b[36m:\x1b[m\x1b[01;31mC is\x1b[m a programming language.",
+        ])]
+        #[case::case_insensitive(&["--color=always", "-i", "rUST", "resources/tree/languages.txt"], &[], &[
+            "\x1b[32m1\x1b[m\x1b[36m:\x1b[m\x1b[01;31mRust\x1b[m is a programming language.",
+        ])]
+        #[case::regex_and_context(&["--color=always", "-EA1", "^R\\w+", "resources/tree/languages.txt"], &[], &[
+            "\x1b[32m1\x1b[m\x1b[36m:\x1b[m\x1b[01;31mRust\x1b[m is a programming language.",
+            "\x1b[32m2\x1b[m\x1b[36m-\x1b[mC is a programming language.",
+        ])]
+        #[case::files_with_matches(&["--color=always", "-l", "Cargo", TREE_DIR], &[], &[
+            "\x1b[35mresources/tree/nested/tools.txt\x1b[m",
+        ])]
+        #[case::never(&["--color=never", "Cargo", TREE_DIR], &[], &[
+            "resources/tree/nested/tools.txt:1:Cargo builds Rust crates.",
+        ])]
+        #[case::auto_not_on_a_terminal(&["--color=auto", "Cargo", TREE_DIR], &[], &[
+            "resources/tree/nested/tools.txt:1:Cargo builds Rust crates.",
+        ])]
+        fn in_found_lines(
+            #[case] args: &[&str],
+            #[case] envs: &[(&str, &str)],
+            #[case] expected_lines: &[&str],
+        ) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, envs));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The colored lines are invalid.",
+            );
+        }
+
+        #[rstest]
+        #[case::matches_and_line_number(&[("GREP_COLORS", "mt=01;34:ln=33")], &[
+            "\x1b[33m1\x1b[m\x1b[36m:\x1b[m\x1b[01;34mRust\x1b[m is a programming language.",
+            "\x1b[33m2\x1b[m\x1b[36m-\x1b[mC is a programming language.",
+        ])]
+        #[case::lines_and_no_separator(&[("GREP_COLORS", "ms=04:sl=1:cx=2:se=:rv:ne")], &[
+            "\x1b[32m1\x1b[m:\x1b[04mRust\x1b[m\x1b[1m is a programming language.\x1b[m",
+            "\x1b[32m2\x1b[m-\x1b[2mC is a programming language.\x1b[m",
+        ])]
+        #[case::context_match(&[("GREP_COLORS", "ms=:mc=07")], &[
+            "\x1b[32m1\x1b[m\x1b[36m:\x1b[mRust is a programming language.",
+            "\x1b[32m2\x1b[m\x1b[36m-\x1b[mC is a programming language.",
+        ])]
+        fn with_a_theme(#[case] envs: &[(&str, &str)], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &[
+                    "--color=always",
+                    "-A1",
+                    "Rust",
+                    "resources/tree/languages.txt",
+                ],
+                envs,
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The colored lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_theme() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["--color=always", "Rust", "resources/tree/languages.txt"],
+                &[("GREP_COLORS", "ms=red")],
+            ));
+
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout,
+                "\x1b[32m1\x1b[m\x1b[36m:\x1b[m\x1b[01;31mRust\x1b[m is a programming language.\n",
+                "The default colors are not used.",
+            );
+            assert!(
+                stderr.contains("The entry 'ms=red' of the variable 'GREP_COLORS' is invalid"),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_choice() {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["--color=sometimes", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(
+                    "error 136: The value 'sometimes' of the option '--color' is invalid."
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_a_json_output {
+        use super::*;
+
+        #[test]
+        fn with_many_files() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["--json", "-B1", "C", TREE_DIR, "--color=always"],
+                &[],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                [
+                    r#"{"type":"begin","data":{"path":{"text":"resources/tree/languages.txt"}}}"#,
+                    r#"{"type":"context","data":{"path":{"text":"resources/tree/languages.txt"},"lines":{"text":"Rust is a programming language."},"line_number":1,"absolute_offset":0,"submatches":[]}}"#,
+                    r#"{"type":"match","data":{"path":{"text":"resources/tree/languages.txt"},"lines":{"text":"C is a programming language."},"line_number":2,"absolute_offset":32,"submatches":[{"match":{"text":"C"},"start":0,"end":1}]}}"#,
+                    r#"{"type":"end","data":{"path":{"text":"resources/tree/languages.txt"},"stats":{"matched_lines":1}}}"#,
+                    r#"{"type":"begin","data":{"path":{"text":"resources/tree/nested/tools.txt"}}}"#,
+                    r#"{"type":"match","data":{"path":{"text":"resources/tree/nested/tools.txt"},"lines":{"text":"Cargo builds Rust crates."},"line_number":1,"absolute_offset":0,"submatches":[{"match":{"text":"C"},"start":0,"end":1}]}}"#,
+                    r#"{"type":"match","data":{"path":{"text":"resources/tree/nested/tools.txt"},"lines":{"text":"Make builds C programs."},"line_number":2,"absolute_offset":26,"submatches":[{"match":{"text":"C"},"start":12,"end":13}]}}"#,
+                    r#"{"type":"end","data":{"path":{"text":"resources/tree/nested/tools.txt"},"stats":{"matched_lines":2}}}"#,
+                    r#"{"type":"summary","data":{"stats":{"searched_files":2,"matched_files":2,"matched_lines":3}}}"#,
+                ],
+                "The JSON output is invalid.",
+            );
+        }
+
+        #[test]
+        fn with_escaped_text() {
+            let (stdout, stderr, code) = run_with_stdin(
+                mini_grep_cmd_with(&["--json", "-E", "a.c"], &[]),
+                "x\r\n\"a\tc\" \\ \u{1}\n",
+            );
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().nth(1),
+                Some(
+                    r#"{"type":"match","data":{"path":{"text":"(standard input)"},"lines":{"text":"\"a\tc\" \\ \u0001"},"line_number":2,"absolute_offset":3,"submatches":[{"match":{"text":"a\tc"},"start":1,"end":4}]}}"#
+                ),
+                "The JSON output is invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_missing_file() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["--json", "Rust", "missing.txt", EXAMPLE_FILE],
+                &[],
+            ));
+
+            assert!(
+                stderr.contains("error 132: The file 'missing.txt' does not exist."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().next(),
+                Some(
+                    r#"{"type":"error","data":{"path":{"text":"missing.txt"},"code":132,"message":"The file 'missing.txt' does not exist."}}"#
+                ),
+                "The JSON output is invalid.",
+            );
+            assert_eq!(
+                stdout.lines().last(),
+                Some(
+                    r#"{"type":"summary","data":{"stats":{"searched_files":1,"matched_files":1,"matched_lines":5}}}"#
+                ),
+                "The JSON output is invalid.",
+            );
+        }
+
+        #[test]
+        fn without_found_line() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["--json", "-c", "Python", TREE_DIR],
+                &[],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(1), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().last(),
+                Some(
+                    r#"{"type":"summary","data":{"stats":{"searched_files":2,"matched_files":0,"matched_lines":0}}}"#
+                ),
+                "The JSON output is invalid.",
+            );
+        }
+    }
+
+    mod with_only_matching {
+        use super::*;
+
+        const INPUT: &str = "GET /a?id=42 id=7\nnothing here\nPOST /b?id=1234\n";
+
+        #[rstest]
+        #[case::many_matches_per_line(&["-o", "-E", "id=[0-9]+"], &["1:8:id=42", "1:14:id=7", "3:9:id=1234"])]
+        #[case::with_context(&["--only-matching", "-C1", "-E", "/[a-z]"], &["1:5:/a", "3:6:/b"])]
+        #[case::case_insensitive(&["-oi", "get"], &["1:1:GET"])]
+        #[case::empty_matches(&["-oE", "[0-9]*"], &["1:11:42", "1:17:7", "3:12:1234"])]
+        #[case::inverted(&["-ov", "id"], &[])]
+        #[case::banner(&["-o", "--banner", "POST"], &["The standard input contains these lines with the case sensitive pattern 'POST':", "3:1: POST"])]
+        fn in_the_standard_input(#[case] args: &[&str], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The matched texts are invalid.",
+            );
+        }
+
+        #[test]
+        fn in_many_files() {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(
+                &["-o", "-E", "[A-Z][a-z]+", TREE_DIR, "--color=always"],
+                &[("GREP_COLORS", "ln=:se=:fn=")],
+            ));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                [
+                    "resources/tree/languages.txt:1:1:\x1b[01;31mRust\x1b[m",
+                    "resources/tree/nested/tools.txt:1:1:\x1b[01;31mCargo\x1b[m",
+                    "resources/tree/nested/tools.txt:1:14:\x1b[01;31mRust\x1b[m",
+                    "resources/tree/nested/tools.txt:2:1:\x1b[01;31mMake\x1b[m",
+                ],
+                "The matched texts are invalid.",
+            );
+        }
+    }
+
+    mod with_columns {
+        use super::*;
+
+        const INPUT: &str = "héllo wörld\ne\u{301}x wörld\nnothing\n";
+
+        #[rstest]
+        #[case::bytes(&["--column", "wörld"], &["1:8:héllo wörld", "2:6:e\u{301}x wörld"])]
+        #[case::chars(&["--column", "--column-unit=chars", "wörld"], &["1:7:héllo wörld", "2:5:e\u{301}x wörld"])]
+        #[case::graphemes(&["--column", "--column-unit", "graphemes", "wörld"], &["1:7:héllo wörld", "2:4:e\u{301}x wörld"])]
+        #[case::byte_offset(&["-b", "wörld"], &["1:0:héllo wörld", "2:14:e\u{301}x wörld"])]
+        #[case::column_and_byte_offset(&["--column", "-b", "-A1", "x"], &["2:4:14:e\u{301}x wörld", "3-1-26-nothing"])]
+        #[case::only_matching(&["-ob", "--column-unit=chars", "ö"], &["1:8:8:ö", "2:6:20:ö"])]
+        #[case::vimgrep(&["--vimgrep", "-C1", "-E", "[lx]"], &[
+            "(standard input):1:4:héllo wörld",
+            "(standard input):1:5:héllo wörld",
+            "(standard input):1:12:héllo wörld",
+            "(standard input):2:4:e\u{301}x wörld",
+            "(standard input):2:10:e\u{301}x wörld",
+        ])]
+        #[case::vimgrep_byte_offset(&["--vimgrep", "-b", "l"], &[
+            "(standard input):1:4:3:héllo wörld",
+            "(standard input):1:5:4:héllo wörld",
+            "(standard input):1:12:11:héllo wörld",
+            "(standard input):2:10:23:e\u{301}x wörld",
+        ])]
+        #[case::vimgrep_inverted(&["--vimgrep", "-v", "wörld"], &["(standard input):3:1:nothing"])]
+        fn of_each_match(#[case] args: &[&str], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The columns are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_unit() {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["--column-unit=words", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(
+                    "error 136: The value 'words' of the option '--column-unit' is invalid."
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_invalid_utf8 {
+        use super::*;
+
+        const INPUT: &[u8] = b"caf\xe9 au lait\nthe\xff end\nplain\n";
+
+        #[rstest]
+        #[case::lossy(&["end"], &["2:the\u{fffd} end"])]
+        #[case::escape(&["--invalid-utf8=escape", "end"], &["2:the\\xFF end"])]
+        #[case::lossy_match(&["-E", "caf. au"], &["1:caf\u{fffd} au lait"])]
+        #[case::columns(&["--column", "--column-unit=chars", "--invalid-utf8", "escape", "end"], &["2:6:the\\xFF end"])]
+        #[case::only_matching(&["-ob", "au"], &["1:6:5:au"])]
+        #[case::colors(&["--color=always", "--invalid-utf8=escape", "-v", "a"], &[
+            "\x1b[32m2\x1b[m\x1b[36m:\x1b[mthe\\xFF end",
+        ])]
+        fn in_each_line(#[case] args: &[&str], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_json_output() {
+            let (stdout, stderr, code) =
+                run_with_stdin(mini_grep_cmd_with(&["--json", "end"], &[]), INPUT);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().nth(1),
+                Some(
+                    r#"{"type":"match","data":{"path":{"text":"(standard input)"},"lines":{"bytes":"dGhl/yBlbmQ="},"line_number":2,"absolute_offset":13,"submatches":[{"match":{"text":"end"},"start":5,"end":8}]}}"#
+                ),
+                "The JSON output is invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_mode() {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["--invalid-utf8=hex", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(
+                    "error 136: The value 'hex' of the option '--invalid-utf8' is invalid."
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_binary_files {
+        use super::*;
+
+        #[rstest]
+        #[case::report(&["Rust", BINARY_DIR], &[
+            "Binary file resources/binary/program.bin matches",
+            "resources/binary/readme.txt:1:Rust is a language.",
+        ])]
+        #[case::report_with_context(&["-C1", "-o", "main", BINARY_DIR], &[
+            "Binary file resources/binary/program.bin matches",
+        ])]
+        #[case::skip(&["--binary-files=without-match", "Rust", BINARY_DIR], &[
+            "resources/binary/readme.txt:1:Rust is a language.",
+        ])]
+        #[case::text(&["-a", "again", BINARY_DIR], &[
+            "resources/binary/program.bin:3:Rust again",
+        ])]
+        #[case::count(&["-c", "Rust", BINARY_DIR], &[
+            "resources/binary/program.bin:2",
+            "resources/binary/readme.txt:1",
+        ])]
+        #[case::skip_without_match(&["--binary-files", "without-match", "-L", "Rust", BINARY_DIR], &[
+            "resources/binary/program.bin",
+        ])]
+        fn in_many_files(#[case] args: &[&str], #[case] expected_lines: &[&str]) {
+            let (stdout, stderr, code) = run(mini_grep_cmd_with(args, &[]));
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+
+        #[rstest]
+        #[case::report(&["main"], b"fn main\0\n", &["Binary file (standard input) matches"])]
+        #[case::text(&["--binary-files=text", "main"], b"fn main\0\n", &["1:fn main\0"])]
+        #[case::controls(&["ok"], b"\x01\x02\x03ok\x04\x05\n", &[
+            "Binary file (standard input) matches",
+        ])]
+        #[case::text_with_controls(&["ok"], b"\x1b[1mok\x1b[m\tdone\n", &["1:\x1b[1mok\x1b[m\tdone"])]
+        fn in_the_standard_input(
+            #[case] args: &[&str],
+            #[case] input: &[u8],
+            #[case] expected_lines: &[&str],
+        ) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), input);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+
+        #[rstest]
+        #[case::lines(&["foo"])]
+        #[case::only_matching(&["-o", "foo"])]
+        fn in_an_endless_standard_input(#[case] args: &[&str]) {
+            let (stdout, code) = run_with_endless_stdin(mini_grep_cmd_with(args, &[]), b"foo\0\n");
+
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout, "Binary file (standard input) matches\n",
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_type() {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["--binary-files=skip", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(
+                    "error 136: The value 'skip' of the option '--binary-files' is invalid."
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_an_encoding {
+        use super::*;
+
+        #[rstest]
+        #[case::utf8_bom(&["-E", "^Rust"], b"\xEF\xBB\xBFRust\nC\n", &["1:Rust"])]
+        #[case::utf16le_bom(&["-v", "Rust"], b"\xFF\xFER\0u\0s\0t\0\r\0\n\0C\0 \0\xE9\0\n\0", &["2:C \u{e9}"])]
+        #[case::utf16be_bom(&["-b", "Rust"], b"\xFE\xFF\0a\0\n\0R\0u\0s\0t\xD8\x3D\xDE\x00", &["2:2:Rust\u{1f600}"])]
+        #[case::utf16le(&["--encoding=utf-16le", "Rust"], b"R\0u\0s\0t\0\n\0", &["1:Rust"])]
+        #[case::utf16le_without_bom(&["Rust"], b"R\0u\0s\0t\0\n\0", &[])]
+        #[case::latin1(&["--encoding", "latin-1", "caf\u{e9}"], b"caf\xE9 au lait\n", &["1:caf\u{e9} au lait"])]
+        #[case::utf8(&["--encoding=utf-8", "caf"], b"caf\xE9\n", &["1:caf\u{fffd}"])]
+        fn in_the_standard_input(
+            #[case] args: &[&str],
+            #[case] input: &[u8],
+            #[case] expected_lines: &[&str],
+        ) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), input);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(
+                code,
+                Some(if expected_lines.is_empty() { 1 } else { 0 }),
+                "Bad exit code."
+            );
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_an_invalid_encoding() {
+            let (stdout, stderr, code) =
+                run(mini_grep_cmd_with(&["--encoding=ebcdic", "Rust"], &[]));
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(
+                    "error 136: The value 'ebcdic' of the option '--encoding' is invalid."
+                ),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    #[cfg(unix)]
+    mod with_a_non_unicode_path {
+        use std::ffi::OsStr;
+        use std::os::unix::ffi::OsStrExt;
+        use std::path::PathBuf;
+
+        use super::*;
+
+        /// Build a directory with a file named with bytes that are not valid UTF-8.
+        fn directory_with_a_non_unicode_file(name: &str) -> (PathBuf, PathBuf) {
+            let directory = std::env::temp_dir().join(name);
+            let _ = std::fs::remove_dir_all(&directory);
+            std::fs::create_dir_all(&directory).unwrap_or_else(|error| {
+                panic!("Cannot create the directory '{directory:?}'. The error: '{error}'.")
+            });
+
+            let file = directory.join(OsStr::from_bytes(b"caf\xE9.txt"));
+            std::fs::write(&file, "Rust is fast.\n").unwrap_or_else(|error| {
+                panic!("Cannot create a file in '{directory:?}'. The error: '{error}'.")
+            });
+
+            (directory, file)
+        }
+
+        /// Run the command, and get its raw standard output.
+        fn run_raw(mut mini_grep_cmd: Cmd) -> (Vec<u8>, String, Option<i32>) {
+            let output = mini_grep_cmd.output().unwrap_or_else(|error| {
+                panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+            });
+            let stderr =
+                clear_useless_lines_from(String::from_utf8_lossy(&output.stderr).into_owned());
+
+            (output.stdout, stderr, output.status.code())
+        }
+
+        #[rstest]
+        #[case::lines(&[], b":1:Rust is fast.\n")]
+        #[case::files_with_matches(&["-l"], b"\n")]
+        #[case::count(&["-c"], b":1\n")]
+        fn in_a_directory(#[case] args: &[&str], #[case] expected_end: &[u8]) {
+            let (directory, file) = directory_with_a_non_unicode_file(&format!(
+                "mini_grep_non_unicode{}",
+                args.concat()
+            ));
+            let mut mini_grep_cmd = mini_grep_cmd_with(args, &[]);
+            mini_grep_cmd.arg("Rust").arg(&directory);
+
+            let (stdout, stderr, code) = run_raw(mini_grep_cmd);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout,
+                [file.as_os_str().as_bytes(), expected_end].concat(),
+                "The path is not printed without loss: '{}'.",
+                String::from_utf8_lossy(&stdout),
+            );
+        }
+
+        #[test]
+        fn with_a_json_output() {
+            let (directory, _) = directory_with_a_non_unicode_file("mini_grep_non_unicode_json");
+            let mut mini_grep_cmd = mini_grep_cmd_with(&["--json", "Rust"], &[]);
+            mini_grep_cmd.arg(&directory);
+
+            let (stdout, stderr, code) = run_raw(mini_grep_cmd);
+            let stdout = String::from_utf8(stdout).unwrap_or_else(|error| {
+                panic!("The JSON output is not valid UTF-8. The error: '{error}'.")
+            });
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert!(
+                stdout.lines().next().is_some_and(
+                    |line| line.starts_with(r#"{"type":"begin","data":{"path":{"bytes":""#)
+                ),
+                "The path is not encoded in base64: '{stdout}'.",
+            );
+        }
+
+        #[test]
+        fn not_existing() {
+            let mut mini_grep_cmd = mini_grep_cmd_with(&["Rust"], &[]);
+            mini_grep_cmd.arg(OsStr::from_bytes(b"missing\xFF.txt"));
+
+            let (stdout, stderr, code) = run_raw(mini_grep_cmd);
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout:?}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains("error 132: The file 'missing\u{fffd}.txt' does not exist."),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+
+        #[rstest]
+        #[case::pattern(&[], b"caf\xE9", &[EXAMPLE_FILE])]
+        #[case::option_value(&["--encoding"], b"lat\xFFin-1", &["Rust", EXAMPLE_FILE])]
+        fn as_a_text_argument(
+            #[case] args_before: &[&str],
+            #[case] arg: &[u8],
+            #[case] args_after: &[&str],
+        ) {
+            let mut mini_grep_cmd = mini_grep_cmd_with(args_before, &[]);
+            mini_grep_cmd.arg(OsStr::from_bytes(arg)).args(args_after);
+
+            let (stdout, stderr, code) = run_raw(mini_grep_cmd);
+
+            assert!(stdout.is_empty(), "Standard output: '{stdout:?}'.");
+            assert_eq!(code, Some(2), "Bad exit code.");
+            assert!(
+                stderr.contains(&format!(
+                    "error 137: The argument '{}' is not valid Unicode.",
+                    String::from_utf8_lossy(arg),
+                )),
+                "Bad error in stderr: '{stderr}'.",
+            );
+        }
+    }
+
+    mod with_case_folding {
+        use super::*;
+
+        #[rstest]
+        #[case::sharp_s(&["-i", "STRASSE"], "Die Straße.\n", &["1:Die Straße."])]
+        #[case::final_sigma_in_the_line(&["-i", "ΟΔΟΣ"], "η οδος\n", &["1:η οδος"])]
+        #[case::final_sigma_in_the_pattern(&["-i", "οδος"], "Η ΟΔΟΣ\n", &["1:Η ΟΔΟΣ"])]
+        #[case::ligature(&["-io", "FILE"], "a ﬁle\n", &["1:3:ﬁle"])]
+        #[case::inside_a_folding(&["-io", "s"], "ß\nss\n", &["2:1:s", "2:2:s"])]
+        #[case::after_a_folding(&["-io", "ss"], "sß\n", &["1:2:ß"])]
+        #[case::span_in_the_original(&["-i", "--column", "-o", "STANBUL"], "İstanbul\n", &[
+            "1:3:stanbul",
+        ])]
+        #[case::ascii(&["-io", "rust"], "RUST Rust rust\n", &["1:1:RUST", "1:6:Rust", "1:11:rust"])]
+        #[case::ascii_without_overlap(&["-io", "AA"], "aaa\n", &["1:1:aa"])]
+        #[case::ascii_pattern_in_a_folding(&["-io", "K"], "\u{212A}elvin k\n", &[
+            "1:1:\u{212A}",
+            "1:10:k",
+        ])]
+        #[case::regex(&["-iE", "ΟΔΟ."], "η οδος\n", &["1:η οδος"])]
+        fn with_an_insensitive_case(
+            #[case] args: &[&str],
+            #[case] input: &str,
+            #[case] expected_lines: &[&str],
+        ) {
+            let (stdout, stderr, code) = run_with_stdin(mini_grep_cmd_with(args, &[]), input);
+
+            assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+            assert_eq!(code, Some(0), "Bad exit code.");
+            assert_eq!(
+                stdout.lines().collect::<Vec<_>>(),
+                expected_lines,
+                "The found lines are invalid.",
+            );
+        }
+    }
+
+    #[test]
+    fn with_a_closed_output() {
+        use std::io::Write;
+        use std::process::Stdio;
+
+        let mut mini_grep_cmd = mini_grep_cmd_with(&["line"], &[]);
+        mini_grep_cmd
+            .stdin(Stdio::piped())
+            .stdout(Stdio::piped())
+            .stderr(Stdio::piped());
+
+        let mut child = mini_grep_cmd.spawn().unwrap_or_else(|error| {
+            panic!("Error during the spawn of the command mini-grep, the error: '{error}'.")
+        });
+
+        // The output is closed before the first found line is printed.
+        drop(child.stdout.take());
+        if let Some(mut stdin) = child.stdin.take() {
+            // The search stops before the end of the input.
+            let _ = stdin.write_all("line\n".repeat(100_000).as_bytes());
+        }
+
+        let output = child.wait_with_output().unwrap_or_else(|error| {
+            panic!("Error during the wait of the command mini-grep, the error: '{error}'.")
+        });
+        let stderr = clear_useless_lines_from(String::from_utf8_lossy(&output.stderr).into_owned());
+
+        assert!(stderr.is_empty(), "Standard error output: '{stderr}'.");
+        assert_eq!(output.status.code(), Some(0), "Bad exit code.");
+    }
+
+    mod with_the_library {
+        use super::super::errors::{InvalidArgumentError, ReadError};
+        use std::io::{self, Read};
+        use std::ops::Range;
+        use std::path::Path;
+
+        use super::super::{
+            BinaryPolicy, Encoding, ExitStatus, InvalidUtf8, LineKind, Match, Matcher, Parsed,
+            PatternSyntax, SearchedFile, Searcher, Sink,
+        };
+        use super::*;
+
+        /// Fail at each read.
+        struct Broken;
+
+        impl Read for Broken {
+            fn read(&mut self, _buf: &mut [u8]) -> io::Result<usize> {
+                Err(io::Error::other("broken"))
+            }
+        }
+
+        #[test]
+        fn with_a_reader() {
+            let searcher = Searcher::builder("b").before_context(1).build().unwrap();
+            let input = b"a\nb\nc\n".chain(Broken);
+
+            let results: Vec<_> = searcher.search(input).collect();
+
+            assert_eq!(results.len(), 3, "Bad number of results: {results:?}.");
+            let context = results[0].as_ref().unwrap();
+            assert_eq!((context.kind, context.line_number), (LineKind::Context, 1));
+            let found = results[1].as_ref().unwrap();
+            assert_eq!((found.kind, found.line_number), (LineKind::Match, 2));
+            assert_eq!(found.line_range, 2..3, "Bad byte range of the line.");
+            assert!(
+                matches!(results[2], Err(ReadError::Line(4, _))),
+                "The line 4 is readable: {results:?}.",
+            );
+        }
+
+        #[test]
+        fn with_invalid_utf8() {
+            let searcher = Searcher::builder("é")
+                .case_sensitive(false)
+                .build()
+                .unwrap();
+            let input: &[u8] = b"caf\xe9\nx\xff\xfe\xc3\x89t\xc3\xa9\n";
+
+            let found: Vec<_> = searcher.search_bytes(input).collect();
+
+            assert_eq!(found.len(), 1, "Bad number of found lines: {found:?}.");
+            assert_eq!(found[0].line_number, 2, "Bad line number.");
+            assert_eq!(found[0].line_range, 5..13, "Bad byte range of the line.");
+            assert_eq!(found[0].line(), "x\u{fffd}\u{fffd}Été", "Bad lossy line.");
+            let submatches: Vec<_> = found[0]
+                .submatches
+                .iter()
+                .map(|submatch| submatch.range.clone())
+                .collect();
+            assert_eq!(
+                submatches,
+                [3..5, 6..8],
+                "Bad byte ranges of the matched texts."
+            );
+            assert_eq!(
+                InvalidUtf8::Escape.decode(&found[0].bytes),
+                "x\\xFF\\xFEÉté",
+                "Bad escaped line.",
+            );
+        }
+
+        #[test]
+        fn with_capture_groups() {
+            let searcher = Searcher::builder(r"(\w+)=(\d+)?")
+                .syntax(PatternSyntax::Regex)
+                .build()
+                .unwrap();
+
+            let found: Vec<_> = searcher.search_str("id=42 name= x").collect();
+
+            assert_eq!(found.len(), 1, "Bad number of found lines.");
+            let submatches: Vec<_> = found[0]
+                .submatches
+                .iter()
+                .map(|submatch| {
+                    (
+                        found[0].text(submatch).into_owned(),
+                        submatch.groups.clone(),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                submatches,
+                [
+                    ("id=42".to_owned(), vec![Some(0..2), Some(3..5)]),
+                    ("name=".to_owned(), vec![Some(6..10), None]),
+                ],
+                "The submatches are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_directory() {
+            let searcher = Searcher::builder("RUST")
+                .case_sensitive(false)
+                .invert_match(true)
+                .build()
+                .unwrap();
+
+            let files: Vec<_> = searcher
+                .search_path(TREE_DIR)
+                .unwrap()
+                .into_iter()
+                .map(|file| {
+                    let lines: Vec<_> = file
+                        .lines
+                        .into_iter()
+                        .map(|line| line.unwrap().line().into_owned())
+                        .collect();
+                    (file.path, lines)
+                })
+                .collect();
+
+            assert_eq!(
+                files,
+                [
+                    (
+                        Path::new("resources/tree/languages.txt").to_path_buf(),
+                        vec!["C is a programming language.".to_owned()],
+                    ),
+                    (
+                        Path::new("resources/tree/nested/tools.txt").to_path_buf(),
+                        vec!["Make builds C programs.".to_owned()],
+                    ),
+                ],
+                "The found lines are invalid.",
+            );
+        }
+
+        /// Find the shortest text containing all characters of the pattern, in order.
+        #[derive(Debug)]
+        struct Fuzzy(&'static str);
+
+        impl Matcher for Fuzzy {
+            fn find(&self, line: &str) -> Option<Range<usize>> {
+                line.char_indices()
+                    .filter_map(|(start, _)| {
+                        let mut chars = self.0.chars().peekable();
+                        let end = line[start..].char_indices().find_map(|(offset, c)| {
+                            chars.next_if_eq(&c);
+                            chars
+                                .peek()
+                                .is_none()
+                                .then(|| start + offset + c.len_utf8())
+                        })?;
+                        Some(start..end)
+                    })
+                    .min_by_key(|range| (range.len(), range.start))
+            }
+        }
+
+        #[test]
+        fn with_a_custom_matcher() {
+            let searcher = Searcher::builder("rst")
+                .after_context(1)
+                .build_with(Fuzzy("rst"));
+
+            let found: Vec<_> = searcher
+                .search_str("C is old.\nRust is fast, rust is safe.\nThe end.\n")
+                .map(|found| {
+                    let texts: Vec<_> = found
+                        .submatches
+                        .iter()
+                        .map(|submatch| found.text(submatch).into_owned())
+                        .collect();
+                    (found.kind, found.line_number, texts)
+                })
+                .collect();
+
+            assert_eq!(
+                found,
+                [
+                    (LineKind::Match, 2, vec!["rust".to_owned()]),
+                    (LineKind::Context, 3, Vec::new()),
+                ],
+                "The found lines are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_empty_matches() {
+            /// Find the empty text before each digit.
+            #[derive(Debug)]
+            struct BeforeDigit;
+
+            impl Matcher for BeforeDigit {
+                fn find(&self, line: &str) -> Option<Range<usize>> {
+                    let start = line.find(|c: char| c.is_ascii_digit())?;
+                    Some(start..start)
+                }
+            }
+
+            assert_eq!(BeforeDigit.find_all("a1é22"), [1..1, 4..4, 5..5]);
+            assert!(!BeforeDigit.is_match("abc"));
+        }
+
+        #[test]
+        fn with_a_sink() {
+            /// Record each event of the search.
+            #[derive(Default)]
+            struct Recorder(Vec<String>);
+
+            impl Sink for Recorder {
+                fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+                    self.0.push(format!("begin {}", path.display()));
+                    Ok(())
+                }
+
+                fn matched(&mut self, _path: &Path, found: Match) -> io::Result<bool> {
+                    self.0.push(format!("match {}", found.line_number));
+                    Ok(true)
+                }
+
+                fn context(&mut self, _path: &Path, line: Match) -> io::Result<()> {
+                    self.0.push(format!("context {}", line.line_number));
+                    Ok(())
+                }
+
+                fn read_error(&mut self, _path: &Path, error: ReadError) -> io::Result<()> {
+                    let ReadError::Line(line_number, _) = error;
+                    self.0.push(format!("error {line_number}"));
+                    Ok(())
+                }
+
+                fn end_file(&mut self, path: &Path) -> io::Result<()> {
+                    self.0.push(format!("end {}", path.display()));
+                    Ok(())
+                }
+            }
+
+            let searcher = Searcher::builder("b").before_context(1).build().unwrap();
+            let mut recorder = Recorder::default();
+
+            searcher
+                .search_to("input", b"a\n\xff\nb\n".chain(Broken), &mut recorder)
+                .unwrap();
+
+            assert_eq!(
+                recorder.0,
+                [
+                    "begin input",
+                    "context 2",
+                    "match 3",
+                    "error 4",
+                    "end input"
+                ],
+                "The events are invalid.",
+            );
+        }
+
+        #[rstest]
+        #[case::report(BinaryPolicy::Report, &["binary", "match 1"])]
+        #[case::skip(BinaryPolicy::Skip, &[])]
+        #[case::text(BinaryPolicy::Text, &["match 1"])]
+        fn with_a_binary_file(#[case] binary: BinaryPolicy, #[case] expected_events: &[&str]) {
+            /// Record the binary files and the selected lines.
+            #[derive(Default)]
+            struct Recorder(Vec<String>);
+
+            impl Sink for Recorder {
+                fn binary_file(&mut self, _path: &Path) -> io::Result<()> {
+                    self.0.push("binary".to_owned());
+                    Ok(())
+                }
+
+                fn matched(&mut self, _path: &Path, found: Match) -> io::Result<bool> {
+                    self.0.push(format!("match {}", found.line_number));
+                    Ok(true)
+                }
+            }
+
+            let searcher = Searcher::builder("main").binary(binary).build().unwrap();
+            let mut recorder = Recorder::default();
+
+            searcher
+                .search_to("program", &b"fn main\0\nend\n"[..], &mut recorder)
+                .unwrap();
+
+            assert_eq!(recorder.0, expected_events, "The events are invalid.");
+            assert_eq!(
+                searcher.search_bytes(b"fn main\0\nend\n").count(),
+                1,
+                "The binary file is not searched as text.",
+            );
+        }
+
+        #[rstest]
+        #[case::auto(Encoding::Auto, b"\xFF\xFEa\0\n\0=\xD8\0\xDE \0\n\0")]
+        #[case::utf16le(Encoding::Utf16Le, b"a\0\n\0=\xD8\0\xDE \0\n\0")]
+        fn with_a_reader_read_byte_by_byte(#[case] encoding: Encoding, #[case] input: &[u8]) {
+            /// Read one byte at each read.
+            struct ByteByByte<'a>(&'a [u8]);
+
+            impl Read for ByteByByte<'_> {
+                fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+                    let read = self.0.len().min(buffer.len()).min(1);
+                    buffer[..read].copy_from_slice(&self.0[..read]);
+                    self.0 = &self.0[read..];
+                    Ok(read)
+                }
+            }
+
+            let searcher = Searcher::builder("\u{1f600}")
+                .encoding(encoding)
+                .build()
+                .unwrap();
+            let lines: Vec<_> = searcher
+                .search(ByteByByte(input))
+                .collect::<Result<_, _>>()
+                .unwrap();
+
+            assert_eq!(lines.len(), 1, "The found lines are invalid.");
+            assert_eq!(
+                lines[0].line(),
+                "\u{1f600} ",
+                "The decoded line is invalid."
+            );
+            assert_eq!(lines[0].line_range, 2..7, "The line range is invalid.");
+        }
+
+        #[test]
+        fn with_a_collecting_sink() {
+            let searcher = Searcher::builder("is").build().unwrap();
+            let mut files: Vec<SearchedFile> = Vec::new();
+
+            searcher
+                .search_to("first", "Rust is fast.\nThe end.\n".as_bytes(), &mut files)
+                .unwrap();
+            searcher
+                .search_to("second", "C is old.\n".as_bytes(), &mut files)
+                .unwrap();
+
+            let files: Vec<_> = files
+                .into_iter()
+                .map(|file| {
+                    let lines: Vec<_> = file
+                        .lines
+                        .into_iter()
+                        .map(|line| line.unwrap().line_number)
+                        .collect();
+                    (file.path, lines)
+                })
+                .collect();
+            assert_eq!(
+                files,
+                [
+                    (Path::new("first").to_path_buf(), vec![1]),
+                    (Path::new("second").to_path_buf(), vec![1])
+                ],
+                "The collected files are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_failing_sink() {
+            /// Fail at the first selected line.
+            struct Failing(usize);
+
+            impl Sink for Failing {
+                fn matched(&mut self, _path: &Path, _found: Match) -> io::Result<bool> {
+                    self.0 += 1;
+                    Err(io::Error::new(io::ErrorKind::BrokenPipe, "closed"))
+                }
+            }
+
+            let searcher = Searcher::builder("a").build().unwrap();
+            let mut sink = Failing(0);
+
+            let result = searcher.search_to("input", "a\na\na\n".as_bytes(), &mut sink);
+
+            assert_eq!(
+                result.map_err(|error| error.kind()),
+                Err(io::ErrorKind::BrokenPipe),
+                "The error of the sink is not returned.",
+            );
+            assert_eq!(sink.0, 1, "The search does not stop after the error.");
+
+            let mut sink = Failing(0);
+            let result = searcher.search_path_to(TREE_DIR, &mut sink);
+
+            assert_eq!(
+                result.map_err(|error| error.kind()),
+                Err(io::ErrorKind::BrokenPipe),
+                "The error of the sink is not returned for a path.",
+            );
+            assert_eq!(sink.0, 1, "The walk does not stop after the error.");
+        }
+
+        #[test]
+        fn with_a_path_sent_to_a_sink() {
+            /// Record the paths and the errors of the search.
+            #[derive(Default)]
+            struct Recorder(Vec<String>);
+
+            impl Sink for Recorder {
+                fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+                    self.0.push(format!("begin {}", path.display()));
+                    Ok(())
+                }
+
+                fn matched(&mut self, _path: &Path, found: Match) -> io::Result<bool> {
+                    self.0.push(format!("match {}", found.line_number));
+                    Ok(true)
+                }
+
+                fn open_error(
+                    &mut self,
+                    path: &Path,
+                    _error: InvalidArgumentError,
+                ) -> io::Result<()> {
+                    self.0.push(format!("error {}", path.display()));
+                    Ok(())
+                }
+            }
+
+            let searcher = Searcher::builder("Rust").build().unwrap();
+            let mut sink = Recorder::default();
+
+            searcher.search_path_to(TREE_DIR, &mut sink).unwrap();
+            searcher.search_path_to("not_found.txt", &mut sink).unwrap();
+
+            assert_eq!(
+                sink.0,
+                [
+                    "begin resources/tree/languages.txt",
+                    "match 1",
+                    "begin resources/tree/nested/tools.txt",
+                    "match 1",
+                    "error not_found.txt",
+                ],
+                "The events of the search are invalid.",
+            );
+        }
+
+        #[test]
+        fn with_a_stopping_sink() {
+            /// Stop each file at its first selected line.
+            #[derive(Default)]
+            struct First(Vec<String>);
+
+            impl Sink for First {
+                fn matched(&mut self, _path: &Path, found: Match) -> io::Result<bool> {
+                    self.0.push(format!("match {}", found.line_number));
+                    Ok(false)
+                }
+
+                fn end_file(&mut self, path: &Path) -> io::Result<()> {
+                    self.0.push(format!("end {}", path.display()));
+                    Ok(())
+                }
+            }
+
+            let searcher = Searcher::builder("a").build().unwrap();
+            let mut sink = First::default();
+
+            searcher
+                .search_to("input", "b\na\na\n".as_bytes().chain(Broken), &mut sink)
+                .unwrap();
+
+            assert_eq!(
+                sink.0,
+                ["match 2", "end input"],
+                "The search does not stop."
+            );
+        }
+
+        #[test]
+        fn with_many_threads() {
+            use std::thread;
+
+            let searcher = Searcher::builder("is").build().unwrap();
+            let texts = ["Rust is fast.\nC is old.\n", "The end.\n"];
+
+            let counts: Vec<_> = thread::scope(|scope| {
+                let handles: Vec<_> = texts
+                    .map(|text| scope.spawn(|| searcher.search_str(text).count()))
+                    .into_iter()
+                    .collect();
+
+                handles
+                    .into_iter()
+                    .map(|handle| handle.join().unwrap())
+                    .collect()
+            });
+
+            assert_eq!(counts, [2, 0], "Bad counts of found lines.");
+        }
+
+        #[test]
+        fn with_bytes_and_a_buffered_reader() {
+            use std::io::BufReader;
+
+            let searcher = Searcher::builder("b").build().unwrap();
+            let bytes: &[u8] = b"a\nb\n\xff\nab\n";
+
+            let from_bytes: Vec<_> = searcher
+                .search_bytes(bytes)
+                .map(|found| found.line_number)
+                .collect();
+            let reader = BufReader::new(&b"c\n"[..]).chain(bytes);
+            let from_reader: Vec<_> = searcher
+                .search_buf(reader)
+                .map(|result| result.unwrap().line_number)
+                .collect();
+
+            assert_eq!(from_bytes, [2, 4], "Bad lines of the bytes.");
+            assert_eq!(from_reader, [3, 5], "Bad lines of the reader.");
+        }
+
+        #[rstest]
+        #[case::not_found(&["mini-grep", "-i", "RUST"], b"C is old.\n", ExitStatus::NotFound)]
+        #[case::invalid_utf8(&["mini-grep", "-L", "caf"], b"caf\xe9\n", ExitStatus::NotFound)]
+        fn with_a_reader_in_the_command(
+            #[case] args: &[&str],
+            #[case] input: &'static [u8],
+            #[case] expected_status: ExitStatus,
+        ) {
+            let command = Command::try_from_iter(args.iter().map(|arg| arg.to_string()))
+                .unwrap()
+                .with_reader("input", input);
+
+            assert_eq!(command.execute(), expected_status, "Bad exit status.");
+        }
+
+        #[rstest]
+        #[case::without_arguments(&[], 126, 2, "Missing arguments.")]
+        #[case::help(&["mini-grep", "--help"], 139, 2, "The option '--help' does not run a search.")]
+        #[case::version(&["mini-grep", "-V", "pattern"], 139, 2, "The option '--version' does not")]
+        fn with_the_arguments_of_a_command(
+            #[case] args: &[&str],
+            #[case] expected_code: i32,
+            #[case] expected_exit_code: i32,
+            #[case] expected_text: &str,
+        ) {
+            let error = Command::try_from_iter(args.iter().map(|arg| arg.to_string()))
+                .expect_err("The command is built.");
+
+            assert_eq!(
+                (error.code(), error.exit_code()),
+                (expected_code, expected_exit_code),
+                "Bad codes.",
+            );
+            assert!(
+                error.to_string().contains(expected_text),
+                "Bad text: '{error}'.",
+            );
+        }
+
+        #[cfg(unix)]
+        #[test]
+        fn with_a_not_readable_file_in_a_directory() {
+            use std::fs::{create_dir_all, write};
+            use std::os::unix::fs::symlink;
+
+            use super::super::errors::MiniGrepArgsError;
+
+            let directory = std::env::temp_dir().join("mini_grep_search_path_with_a_broken_link");
+            let _ = std::fs::remove_dir_all(&directory);
+            create_dir_all(&directory).unwrap_or_else(|error| {
+                panic!("Cannot create the directory '{directory:?}'. The error: '{error}'.")
+            });
+            symlink(directory.join("missing"), directory.join("a_broken_link")).unwrap_or_else(
+                |error| panic!("Cannot create a link in '{directory:?}'. The error: '{error}'."),
+            );
+            write(directory.join("b_file.txt"), "Rust\n").unwrap_or_else(|error| {
+                panic!("Cannot create a file in '{directory:?}'. The error: '{error}'.")
+            });
+
+            let files = Searcher::builder("Rust")
+                .build()
+                .unwrap()
+                .search_path(&directory)
+                .unwrap();
+
+            let summary: Vec<_> = files
+                .iter()
+                .map(|file| {
+                    let code = file.error.as_ref().map(MiniGrepArgsError::code);
+                    (
+                        file.path.strip_prefix(&directory).unwrap(),
+                        code,
+                        file.lines.len(),
+                    )
+                })
+                .collect();
+            assert_eq!(
+                summary,
+                [
+                    (Path::new("a_broken_link"), Some(134), 0),
+                    (Path::new("b_file.txt"), None, 1),
+                ],
+                "Bad searched files.",
+            );
+        }
+
+        #[rstest]
+        #[case::help(&["mini-grep", "--help"], "Usage: mini-grep [OPTIONS]")]
+        #[case::version(&["mini-grep", "-V", "pattern"], env!("CARGO_PKG_VERSION"))]
+        fn with_the_help_or_the_version(#[case] args: &[&str], #[case] expected_text: &str) {
+            let parsed = Parsed::try_from_iter(args.iter().map(|arg| arg.to_string()))
+                .expect("The arguments are not parsed.");
+
+            match parsed {
+                Parsed::Help(text) | Parsed::Version(text) => {
+                    assert!(text.contains(expected_text), "Bad text: '{text}'.")
+                }
+                Parsed::Run(command) => panic!("The command is built: {command}."),
+            }
+        }
+
+        #[rstest]
+        #[case::blank_pattern(Searcher::builder("  ").build().map(|_| ()), 130)]
+        #[case::invalid_regex(Searcher::builder("(").syntax(PatternSyntax::Regex).build().map(|_| ()), 135)]
+        #[case::not_found_path(Searcher::builder("a").build().unwrap().search_path("not_found.txt").map(|_| ()), 132)]
+        fn with_an_error(
+            #[case] result: Result<(), InvalidArgumentError>,
+            #[case] expected_code: i32,
+        ) {
+            use super::super::errors::MiniGrepArgsError;
+
+            assert_eq!(
+                result.map_err(|error| error.code()),
+                Err(expected_code),
+                "Bad error.",
+            );
+        }
+    }
+}
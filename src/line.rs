@@ -0,0 +1,117 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead};
+
+/// A line read from a file, or from the standard input.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Line {
+    /// The number of the line, starting at 1.
+    pub number: usize,
+    /// The byte offset of the start of the line in the file.
+    pub offset: usize,
+    /// The bytes of the line, without its line terminator. They may not be valid
+    /// UTF-8.
+    pub bytes: Vec<u8>,
+}
+
+impl Line {
+    /// Get the text of the line, with each invalid UTF-8 sequence replaced with the
+    /// replacement character `�`.
+    pub fn text(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.bytes)
+    }
+
+    /// Get the original byte offset in the line of each byte of its text, and of
+    /// the end of its text.
+    ///
+    /// # Returns
+    ///
+    /// Returns the offsets, where all bytes of a replacement character have the
+    /// offset of the invalid sequence it replaces.
+    pub fn offsets(&self) -> Vec<usize> {
+        let mut offsets = Vec::with_capacity(self.bytes.len() + 1);
+        let mut offset = 0;
+
+        for chunk in self.bytes.utf8_chunks() {
+            offsets.extend(offset..offset + chunk.valid().len());
+            offset += chunk.valid().len();
+
+            if !chunk.invalid().is_empty() {
+                offsets.resize(
+                    offsets.len() + char::REPLACEMENT_CHARACTER.len_utf8(),
+                    offset,
+                );
+                offset += chunk.invalid().len();
+            }
+        }
+        offsets.push(offset);
+
+        offsets
+    }
+}
+
+/// An [`Iterator`] over the lines of a reader, like [`BufRead::lines()`], keeping
+/// the number and the byte offset of each line.
+///
+/// A line is terminated by `\n` or `\r\n`, and the last line may have no
+/// terminator.
+#[derive(Debug)]
+pub struct Lines<R> {
+    #[doc(hidden)]
+    reader: R,
+    #[doc(hidden)]
+    number: usize,
+    #[doc(hidden)]
+    offset: usize,
+    #[doc(hidden)]
+    done: bool,
+}
+
+impl<R: BufRead> Lines<R> {
+    /// Build a [`Lines`] reading the reader from its start.
+    pub fn new(reader: R) -> Lines<R> {
+        Self {
+            reader,
+            number: 0,
+            offset: 0,
+            done: false,
+        }
+    }
+}
+
+impl<R: BufRead> Iterator for Lines<R> {
+    /// The number of the line, the byte offset of its start, and its bytes, or the
+    /// error if it cannot be read.
+    type Item = (usize, usize, io::Result<Vec<u8>>);
+
+    /// Read the next line, as bytes that may not be valid UTF-8.
+    ///
+    /// The iteration stops after the first error returned by the reader.
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.done {
+            return None;
+        }
+
+        let mut buffer = Vec::new();
+        let read = match self.reader.read_until(b'\n', &mut buffer) {
+            Ok(0) => return None,
+            Ok(read) => read,
+            Err(error) => {
+                self.done = true;
+                return Some((self.number + 1, self.offset, Err(error)));
+            }
+        };
+
+        self.number += 1;
+        let offset = self.offset;
+        self.offset += read;
+
+        if buffer.ends_with(b"\n") {
+            buffer.pop();
+            if buffer.ends_with(b"\r") {
+                buffer.pop();
+            }
+        }
+
+        Some((self.number, offset, Ok(buffer)))
+    }
+}
@@ -2,25 +2,41 @@
 
 #![doc(issue_tracker_base_url = "https://github.com/Xyphenore/mini-grep/issues/")]
 
-use std::env::args;
+use std::env::args_os;
+use std::io::{self, Write};
 use std::process;
 
-use crate::mini_grep::Command;
-
-mod mini_grep;
+use mini_grep::{ExitStatus, Parsed};
 
 /// Executable script to start mini-grep.
 ///
-/// Get args given on CLI and get the environment variable 'IGNORE_CASE'.
+/// Get args given on CLI, that may not be valid Unicode, and get the environment
+/// variable 'IGNORE_CASE'. Exit with the code of the exit status returned by
+/// [`Command::execute()`]: 0 if at least one line is found, 1 if no line is found
+/// and 2 if an error has occurred.
+///
+/// The code identifying the category of an error is printed to stderr before the
+/// error message. The help and the version are printed to stdout, with the exit
+/// code 0.
 ///
 /// # Panics
 ///
-/// - If any method ([`Command::try_from()`] or [`Command::execute()`]) panics.
+/// - If any method ([`Parsed::try_from()`] or [`Command::execute()`]) panics.
+///
+/// [`Command::execute()`]: mini_grep::Command::execute
 fn main() {
-    Command::try_from(args())
-        .unwrap_or_else(|error| {
-            eprintln!("{error}");
-            process::exit(error.code());
-        })
-        .execute()
+    let status = match Parsed::try_from(args_os()) {
+        Ok(Parsed::Run(command)) => command.execute(),
+        Ok(Parsed::Help(text) | Parsed::Version(text)) => {
+            // Like the search, a closed stdout is not an error.
+            let _ = writeln!(io::stdout(), "{text}");
+            ExitStatus::Found
+        }
+        Err(error) => {
+            eprintln!("error {}: {error}", error.code());
+            process::exit(error.exit_code());
+        }
+    };
+
+    process::exit(status.code());
 }
@@ -0,0 +1,122 @@
+use std::fmt::Debug;
+use std::ops::Range;
+
+use super::matching::Submatch;
+
+/// The matching logic of a [`Searcher`](crate::Searcher), finding the searched
+/// pattern in each line.
+///
+/// The [`LiteralMatcher`](crate::LiteralMatcher) and the
+/// [`RegexMatcher`](crate::RegexMatcher) are the matchers built by the
+/// [`SearcherBuilder`](crate::SearcherBuilder), and any other matcher can be given
+/// to [`SearcherBuilder::build_with()`](crate::SearcherBuilder::build_with). The
+/// selection of lines, the context lines and the output are the same for all
+/// matchers.
+///
+/// Only [`Matcher::find()`] must be implemented. The other methods are derived from
+/// it, and can be implemented to be faster, or to give the capture groups.
+///
+/// A matcher is [`Send`] and [`Sync`], so a [`Searcher`](crate::Searcher) can be
+/// shared by many threads.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::ops::Range;
+///
+/// use mini_grep::{Matcher, Searcher};
+///
+/// /// Find the words written in uppercase.
+/// #[derive(Debug)]
+/// struct Shouting;
+///
+/// impl Matcher for Shouting {
+///     fn find(&self, line: &str) -> Option<Range<usize>> {
+///         let mut start = 0;
+///         for word in line.split(' ') {
+///             if word.len() > 1 && word.chars().all(|c| c.is_ascii_uppercase()) {
+///                 return Some(start..start + word.len());
+///             }
+///             start += word.len() + 1;
+///         }
+///         None
+///     }
+/// }
+///
+/// let searcher = Searcher::builder("shouting").build_with(Shouting);
+///
+/// let lines: Vec<_> = searcher
+///     .search_str("Keep CALM and\ncarry on\nDO IT\n")
+///     .map(|found| (found.line_number, found.submatches.len()))
+///     .collect();
+///
+/// assert_eq!(lines, [(1, 1), (3, 2)]);
+/// ```
+pub trait Matcher: Debug + Send + Sync {
+    /// Find the first occurrence of the pattern in the line.
+    ///
+    /// # Returns
+    ///
+    /// Returns the byte range of the first occurrence in the line, or `None` if the
+    /// line does not contain the pattern.
+    fn find(&self, line: &str) -> Option<Range<usize>>;
+
+    /// Check if the line contains the pattern.
+    fn is_match(&self, line: &str) -> bool {
+        self.find(line).is_some()
+    }
+
+    /// Find all non-overlapping occurrences of the pattern in the line.
+    ///
+    /// By default, the pattern is searched again after each occurrence, in the rest
+    /// of the line. A matcher depending on the text before an occurrence, like an
+    /// anchor of a regular expression, must implement this method.
+    ///
+    /// # Returns
+    ///
+    /// Returns the byte ranges of all occurrences in the line, in order.
+    fn find_all(&self, line: &str) -> Vec<Range<usize>> {
+        let mut spans: Vec<Range<usize>> = Vec::new();
+        let mut start = 0;
+
+        while let Some(found) = line.get(start..).and_then(|rest| self.find(rest)) {
+            let span = start + found.start..start + found.end;
+
+            // An empty occurrence is skipped after the previous occurrence, and the
+            // search continues after the next character.
+            start = if span.is_empty() {
+                line[span.end..]
+                    .chars()
+                    .next()
+                    .map_or(line.len() + 1, |next| span.end + next.len_utf8())
+            } else {
+                span.end
+            };
+
+            if !(span.is_empty() && spans.last().is_some_and(|last| last.end == span.start)) {
+                spans.push(span);
+            }
+        }
+
+        spans
+    }
+
+    /// Find all non-overlapping occurrences of the pattern in the line, with their
+    /// capture groups.
+    ///
+    /// By default, the occurrences are the ones of [`Matcher::find_all()`], without
+    /// capture groups.
+    ///
+    /// # Returns
+    ///
+    /// Returns the [`Submatch`] of all occurrences in the line, in order.
+    fn find_submatches(&self, line: &str) -> Vec<Submatch> {
+        self.find_all(line)
+            .into_iter()
+            .map(|range| Submatch {
+                range,
+                groups: Vec::new(),
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,186 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+use std::ops::Range;
+use std::str::FromStr;
+
+use unicode_segmentation::UnicodeSegmentation;
+
+use super::context::LineKind;
+use super::line::Line;
+
+/// Indicate how MiniGrep counts the column of a matched text in its line.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum ColumnUnit {
+    /// Count the bytes of the line.
+    #[default]
+    Bytes,
+    /// Count the Unicode characters (scalar values) of the line.
+    Chars,
+    /// Count the extended grapheme clusters of the line, the characters perceived
+    /// by a user.
+    Graphemes,
+}
+
+impl FromStr for ColumnUnit {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "bytes" => Ok(Self::Bytes),
+            "chars" => Ok(Self::Chars),
+            "graphemes" => Ok(Self::Graphemes),
+            _ => Err(()),
+        }
+    }
+}
+
+/// Indicate how MiniGrep prints the bytes of a line that are not valid UTF-8.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum InvalidUtf8 {
+    /// Replace each invalid sequence with the replacement character `�`.
+    #[default]
+    Lossy,
+    /// Escape each invalid byte like `\xFF`.
+    Escape,
+}
+
+impl InvalidUtf8 {
+    /// Decode the bytes as UTF-8, with the invalid bytes replaced or escaped.
+    ///
+    /// # Returns
+    ///
+    /// Returns the decoded text, borrowed from the bytes if they are valid UTF-8.
+    pub fn decode(self, bytes: &[u8]) -> Cow<'_, str> {
+        match self {
+            Self::Lossy => String::from_utf8_lossy(bytes),
+            Self::Escape => {
+                if let Ok(text) = std::str::from_utf8(bytes) {
+                    return Cow::Borrowed(text);
+                }
+
+                let mut decoded = String::with_capacity(bytes.len() + 8);
+                for chunk in bytes.utf8_chunks() {
+                    decoded.push_str(chunk.valid());
+                    for byte in chunk.invalid() {
+                        let _ = write!(decoded, "\\x{byte:02X}");
+                    }
+                }
+
+                Cow::Owned(decoded)
+            }
+        }
+    }
+}
+
+impl FromStr for InvalidUtf8 {
+    type Err = ();
+
+    fn from_str(value: &str) -> Result<Self, Self::Err> {
+        match value {
+            "lossy" => Ok(Self::Lossy),
+            "escape" => Ok(Self::Escape),
+            _ => Err(()),
+        }
+    }
+}
+
+/// A line yielded by a search, with the byte ranges of the matched text.
+///
+/// A context line has the [`LineKind::Context`] kind. The matched text of a context
+/// line is given too, if the line contains the pattern, for example if the match
+/// is inverted.
+///
+/// The line is kept as bytes, because it may not be valid UTF-8. Its matched texts
+/// are found in its text decoded with [`InvalidUtf8::Lossy`], and their ranges are
+/// the ones of the original bytes.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Match {
+    /// Indicate that the line is selected, or is a context line.
+    pub kind: LineKind,
+    /// The number of the line in the file, starting at 1.
+    pub line_number: usize,
+    /// The byte range of the line in the file, without its line terminator.
+    pub line_range: Range<usize>,
+    /// The bytes of the line, without its line terminator. They may not be valid
+    /// UTF-8.
+    pub bytes: Vec<u8>,
+    /// All non-overlapping matched texts of the pattern in the line, in order.
+    pub submatches: Vec<Submatch>,
+}
+
+/// A matched text of the pattern in a line.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Submatch {
+    /// The byte range of the matched text in the line.
+    pub range: Range<usize>,
+    /// The byte ranges in the line of the capture groups of a regular expression,
+    /// without the whole matched text, or [`None`] for a group that does not
+    /// participate in the match. It is empty with a plain text pattern.
+    pub groups: Vec<Option<Range<usize>>>,
+}
+
+impl Submatch {
+    /// Move the byte ranges of the submatch, found in the lossy text of a line, to
+    /// the original bytes of the line, with the original offset of each byte of the
+    /// lossy text.
+    #[doc(hidden)]
+    pub(super) fn move_to(&mut self, offsets: &[usize]) {
+        let move_range =
+            |range: &mut Range<usize>| *range = offsets[range.start]..offsets[range.end];
+
+        move_range(&mut self.range);
+        self.groups.iter_mut().flatten().for_each(move_range);
+    }
+}
+
+impl Match {
+    /// Build a [`Match`] from a read line.
+    #[doc(hidden)]
+    pub(super) fn new(kind: LineKind, line: Line, submatches: Vec<Submatch>) -> Match {
+        Self {
+            kind,
+            line_number: line.number,
+            line_range: line.offset..line.offset + line.bytes.len(),
+            bytes: line.bytes,
+            submatches,
+        }
+    }
+
+    /// Get the text of the line, with each invalid UTF-8 sequence replaced with the
+    /// replacement character `�`.
+    pub fn line(&self) -> Cow<'_, str> {
+        String::from_utf8_lossy(&self.bytes)
+    }
+
+    /// Get the text of the line at the byte range of the submatch, like
+    /// [`Match::line()`].
+    pub fn text(&self, submatch: &Submatch) -> Cow<'_, str> {
+        String::from_utf8_lossy(self.text_bytes(submatch))
+    }
+
+    /// Get the bytes of the line at the byte range of the submatch.
+    pub fn text_bytes(&self, submatch: &Submatch) -> &[u8] {
+        &self.bytes[submatch.range.clone()]
+    }
+
+    /// Get the column of the byte offset in the line, counted with the unit.
+    ///
+    /// # Returns
+    ///
+    /// Returns the 1-based column. With [`ColumnUnit::Graphemes`], a byte offset
+    /// inside a grapheme cluster gives the column of the next cluster. An invalid
+    /// UTF-8 sequence is counted as one character.
+    ///
+    /// # Panics
+    ///
+    /// If the byte offset is greater than the length of the line.
+    pub fn column(&self, offset: usize, unit: ColumnUnit) -> usize {
+        let before = &self.bytes[..offset];
+
+        1 + match unit {
+            ColumnUnit::Bytes => before.len(),
+            ColumnUnit::Chars => String::from_utf8_lossy(before).chars().count(),
+            ColumnUnit::Graphemes => String::from_utf8_lossy(before).graphemes(true).count(),
+        }
+    }
+}
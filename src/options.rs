@@ -0,0 +1,572 @@
+use std::ffi::OsString;
+use std::fmt::Write;
+use std::iter::Peekable;
+use std::str::FromStr;
+
+use super::errors::InvalidSyntaxError;
+
+/// All options accepted on CLI.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum CliOption {
+    /// Give the pattern, even if it starts with a `-`.
+    Regexp,
+    /// Interpret the pattern as a regular expression.
+    ExtendedRegexp,
+    /// Interpret the pattern as a plain text.
+    FixedStrings,
+    /// Search the pattern case-insensitively.
+    IgnoreCase,
+    /// Search the pattern case-sensitively.
+    NoIgnoreCase,
+    /// Select lines not containing the pattern.
+    InvertMatch,
+    /// Print only the matched text of selected lines.
+    OnlyMatching,
+    /// Print the given number of lines after each selected line.
+    AfterContext,
+    /// Print the given number of lines before each selected line.
+    BeforeContext,
+    /// Print the given number of lines before and after each selected line.
+    Context,
+    /// Print the column of the first matched text of each line.
+    Column,
+    /// Count columns in bytes, characters or grapheme clusters.
+    ColumnUnit,
+    /// Print the byte offset of each line in its file.
+    ByteOffset,
+    /// Print the bytes that are not valid UTF-8 replaced or escaped.
+    InvalidUtf8,
+    /// Decode each file with the encoding, or detect it with its BOM.
+    Encoding,
+    /// Report, skip or search as text each binary file.
+    BinaryFiles,
+    /// Search each binary file as a text file.
+    Text,
+    /// Print only the number of found lines of each file.
+    Count,
+    /// Print only the path of files containing the pattern.
+    FilesWithMatches,
+    /// Print only the path of files not containing the pattern.
+    FilesWithoutMatch,
+    /// Color the output always, never, or only on a terminal.
+    Color,
+    /// Print a sentence introducing found lines.
+    Banner,
+    /// Print the search results as JSON Lines.
+    Json,
+    /// Print each matched text like `path:line_no:column:line`.
+    Vimgrep,
+    /// Print the help and exit.
+    Help,
+    /// Print the version and exit.
+    Version,
+}
+
+/// The description of an option accepted on CLI, used to parse CLI arguments and
+/// to generate the help.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct OptionSpec {
+    /// The option described.
+    pub option: CliOption,
+    /// The short name of the option, used like `-i`.
+    pub short: Option<char>,
+    /// The long name of the option, used like `--ignore-case`.
+    pub long: &'static str,
+    /// The name of the value taken by the option, or [`None`] for a flag.
+    pub value_name: Option<&'static str>,
+    /// The values accepted after the option, as the next argument. If there are
+    /// any, the next argument is the value only if it is one of them, and else the
+    /// option takes the first one, like `--color` alone. The value given with `=`
+    /// is always taken.
+    pub choices: &'static [&'static str],
+    /// The description of the option printed in the help.
+    pub help: &'static str,
+}
+
+/// All options accepted on CLI, in the order of the help.
+pub const OPTIONS: &[OptionSpec] = &[
+    OptionSpec {
+        option: CliOption::Regexp,
+        short: Some('e'),
+        long: "regexp",
+        value_name: Some("PATTERN"),
+        choices: &[],
+        help: "Use PATTERN as the pattern, even if it starts with a '-'.",
+    },
+    OptionSpec {
+        option: CliOption::ExtendedRegexp,
+        short: Some('E'),
+        long: "extended-regexp",
+        value_name: None,
+        choices: &[],
+        help: "Interpret the pattern as a regular expression.",
+    },
+    OptionSpec {
+        option: CliOption::FixedStrings,
+        short: Some('F'),
+        long: "fixed-strings",
+        value_name: None,
+        choices: &[],
+        help: "Interpret the pattern as a plain text (default).",
+    },
+    OptionSpec {
+        option: CliOption::IgnoreCase,
+        short: Some('i'),
+        long: "ignore-case",
+        value_name: None,
+        choices: &[],
+        help: "Search the pattern case-insensitively.",
+    },
+    OptionSpec {
+        option: CliOption::NoIgnoreCase,
+        short: None,
+        long: "no-ignore-case",
+        value_name: None,
+        choices: &[],
+        help: "Search the pattern case-sensitively (default).",
+    },
+    OptionSpec {
+        option: CliOption::InvertMatch,
+        short: Some('v'),
+        long: "invert-match",
+        value_name: None,
+        choices: &[],
+        help: "Select lines not containing the pattern.",
+    },
+    OptionSpec {
+        option: CliOption::OnlyMatching,
+        short: Some('o'),
+        long: "only-matching",
+        value_name: None,
+        choices: &[],
+        help: "Print only each matched text of selected lines, with its column.",
+    },
+    OptionSpec {
+        option: CliOption::AfterContext,
+        short: Some('A'),
+        long: "after-context",
+        value_name: Some("NUM"),
+        choices: &[],
+        help: "Print NUM lines of context after each selected line.",
+    },
+    OptionSpec {
+        option: CliOption::BeforeContext,
+        short: Some('B'),
+        long: "before-context",
+        value_name: Some("NUM"),
+        choices: &[],
+        help: "Print NUM lines of context before each selected line.",
+    },
+    OptionSpec {
+        option: CliOption::Context,
+        short: Some('C'),
+        long: "context",
+        value_name: Some("NUM"),
+        choices: &[],
+        help: "Print NUM lines of context before and after each selected line.",
+    },
+    OptionSpec {
+        option: CliOption::Column,
+        short: None,
+        long: "column",
+        value_name: None,
+        choices: &[],
+        help: "Print the column of the first matched text of each line.",
+    },
+    OptionSpec {
+        option: CliOption::ColumnUnit,
+        short: None,
+        long: "column-unit",
+        value_name: Some("UNIT"),
+        choices: &[],
+        help: "Count columns in 'bytes' (default), 'chars' or 'graphemes'.",
+    },
+    OptionSpec {
+        option: CliOption::ByteOffset,
+        short: Some('b'),
+        long: "byte-offset",
+        value_name: None,
+        choices: &[],
+        help: "Print the byte offset of each line, or matched text, in its file.",
+    },
+    OptionSpec {
+        option: CliOption::InvalidUtf8,
+        short: None,
+        long: "invalid-utf8",
+        value_name: Some("MODE"),
+        choices: &[],
+        help:
+            "Print invalid UTF-8 bytes as '\u{fffd}' ('lossy', default) or as '\\xFF' ('escape').",
+    },
+    OptionSpec {
+        option: CliOption::Encoding,
+        short: None,
+        long: "encoding",
+        value_name: Some("ENC"),
+        choices: &[],
+        help: "Decode files as 'utf-8', 'utf-16le', 'utf-16be' or 'latin-1', or detect it \
+            with their BOM ('auto', default).",
+    },
+    OptionSpec {
+        option: CliOption::BinaryFiles,
+        short: None,
+        long: "binary-files",
+        value_name: Some("TYPE"),
+        choices: &[],
+        help: "Report matching binary files ('binary', default), skip them ('without-match') \
+            or search them ('text').",
+    },
+    OptionSpec {
+        option: CliOption::Text,
+        short: Some('a'),
+        long: "text",
+        value_name: None,
+        choices: &[],
+        help: "Search binary files as text, like '--binary-files=text'.",
+    },
+    OptionSpec {
+        option: CliOption::Count,
+        short: Some('c'),
+        long: "count",
+        value_name: None,
+        choices: &[],
+        help: "Print only the number of selected lines of each file.",
+    },
+    OptionSpec {
+        option: CliOption::FilesWithMatches,
+        short: Some('l'),
+        long: "files-with-matches",
+        value_name: None,
+        choices: &[],
+        help: "Print only the path of files with selected lines.",
+    },
+    OptionSpec {
+        option: CliOption::FilesWithoutMatch,
+        short: Some('L'),
+        long: "files-without-match",
+        value_name: None,
+        choices: &[],
+        help: "Print only the path of files without selected lines.",
+    },
+    OptionSpec {
+        option: CliOption::Color,
+        short: None,
+        long: "color",
+        value_name: Some("WHEN"),
+        choices: &["auto", "always", "never"],
+        help: "Color the output: 'auto' (default, on a terminal), 'always' or 'never'.",
+    },
+    OptionSpec {
+        option: CliOption::Banner,
+        short: None,
+        long: "banner",
+        value_name: None,
+        choices: &[],
+        help: "Print a sentence introducing found lines, or telling that no line is found.",
+    },
+    OptionSpec {
+        option: CliOption::Json,
+        short: None,
+        long: "json",
+        value_name: None,
+        choices: &[],
+        help: "Print a JSON object per line, file and search summary (JSON Lines).",
+    },
+    OptionSpec {
+        option: CliOption::Vimgrep,
+        short: None,
+        long: "vimgrep",
+        value_name: None,
+        choices: &[],
+        help: "Print each matched text like 'path:line:column:line', for editors.",
+    },
+    OptionSpec {
+        option: CliOption::Help,
+        short: Some('h'),
+        long: "help",
+        value_name: None,
+        choices: &[],
+        help: "Print this help and exit.",
+    },
+    OptionSpec {
+        option: CliOption::Version,
+        short: Some('V'),
+        long: "version",
+        value_name: None,
+        choices: &[],
+        help: "Print the version and exit.",
+    },
+];
+
+/// A parsed CLI argument.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub enum Arg {
+    /// An option, with its value if it takes one.
+    Option(CliOption, Option<String>),
+    /// A positional argument (the pattern or a path), that may not be valid
+    /// Unicode.
+    Positional(OsString),
+}
+
+/// Parse CLI arguments, without the executable name.
+///
+/// Accept short options (`-i`), combined short options (`-iE`), short options
+/// with a glued value (`-ePATTERN`), long options (`--ignore-case`), long options
+/// with a value (`--regexp=PATTERN` or `--regexp PATTERN`) and `--` to stop the
+/// parse of options. A lone `-` is a positional argument. An option with
+/// [`OptionSpec::choices`] takes the next argument only if it is one of them.
+///
+/// A positional argument may not be valid Unicode, like a path, but an option and
+/// its value must be valid Unicode.
+///
+/// # Returns
+///
+/// Returns all parsed arguments in the CLI order, or an [`InvalidSyntaxError`] if
+/// an option is unknown, misses its value, receives an unexpected value, or is not
+/// valid Unicode.
+pub fn parse(
+    executable: &str,
+    args: impl Iterator<Item = OsString>,
+) -> Result<Vec<Arg>, InvalidSyntaxError> {
+    let mut args = args.peekable();
+    let mut parsed = Vec::new();
+
+    while let Some(arg) = args.next() {
+        let arg = match arg.into_string() {
+            Ok(arg) => arg,
+            Err(arg) if arg.to_string_lossy().starts_with('-') => {
+                return Err(InvalidSyntaxError::NotUnicode(
+                    executable.to_owned(),
+                    arg.to_string_lossy().into_owned(),
+                ));
+            }
+            Err(arg) => {
+                parsed.push(Arg::Positional(arg));
+                continue;
+            }
+        };
+
+        if arg == "--" {
+            parsed.extend(args.by_ref().map(Arg::Positional));
+        } else if let Some(long) = arg.strip_prefix("--") {
+            let (name, value) = match long.split_once('=') {
+                Some((name, value)) => (name, Some(value.to_owned())),
+                None => (long, None),
+            };
+
+            let spec = OPTIONS
+                .iter()
+                .find(|spec| spec.long == name)
+                .ok_or_else(|| {
+                    InvalidSyntaxError::UnknownOption(
+                        executable.to_owned(),
+                        format!("--{name}"),
+                        suggest(name).map(|spec| format!("--{}", spec.long)),
+                    )
+                })?;
+
+            let value = match (spec.value_name, value) {
+                (None, Some(_)) => {
+                    return Err(InvalidSyntaxError::UnexpectedValue(
+                        executable.to_owned(),
+                        format!("--{name}"),
+                    ))
+                }
+                (None, None) => None,
+                (Some(_), Some(value)) => Some(value),
+                (Some(_), None) => Some(next_value(
+                    executable,
+                    &format!("--{name}"),
+                    spec,
+                    &mut args,
+                )?),
+            };
+
+            parsed.push(Arg::Option(spec.option, value));
+        } else if let Some(shorts) = arg.strip_prefix('-').filter(|shorts| !shorts.is_empty()) {
+            for (index, short) in shorts.char_indices() {
+                let spec = OPTIONS
+                    .iter()
+                    .find(|spec| spec.short == Some(short))
+                    .ok_or_else(|| {
+                        InvalidSyntaxError::UnknownOption(
+                            executable.to_owned(),
+                            format!("-{short}"),
+                            None,
+                        )
+                    })?;
+
+                if spec.value_name.is_none() {
+                    parsed.push(Arg::Option(spec.option, None));
+                    continue;
+                }
+
+                let glued_value = &shorts[index + short.len_utf8()..];
+                let value = if glued_value.is_empty() {
+                    next_value(executable, &format!("-{short}"), spec, &mut args)?
+                } else {
+                    glued_value.to_owned()
+                };
+
+                parsed.push(Arg::Option(spec.option, Some(value)));
+                break;
+            }
+        } else {
+            parsed.push(Arg::Positional(arg.into()));
+        }
+    }
+
+    Ok(parsed)
+}
+
+/// Get the next argument, as the value of the option. If the option has
+/// [`OptionSpec::choices`], the next argument is taken only if it is one of them,
+/// and else the first choice is the value.
+///
+/// # Returns
+///
+/// Returns the value, or an [`InvalidSyntaxError`] if there is no next argument or
+/// if it is not valid Unicode.
+#[doc(hidden)]
+fn next_value<I: Iterator<Item = OsString>>(
+    executable: &str,
+    option: &str,
+    spec: &OptionSpec,
+    args: &mut Peekable<I>,
+) -> Result<String, InvalidSyntaxError> {
+    if let Some(default) = spec.choices.first() {
+        let is_choice = args
+            .peek()
+            .and_then(|next| next.to_str())
+            .is_some_and(|next| spec.choices.contains(&next));
+        if !is_choice {
+            return Ok((*default).to_owned());
+        }
+    }
+
+    args.next()
+        .ok_or_else(|| InvalidSyntaxError::MissingValue(executable.to_owned(), option.to_owned()))?
+        .into_string()
+        .map_err(|value| {
+            InvalidSyntaxError::NotUnicode(
+                executable.to_owned(),
+                value.to_string_lossy().into_owned(),
+            )
+        })
+}
+
+/// Parse the value of an option.
+///
+/// # Returns
+///
+/// Returns the parsed value, or an [`InvalidSyntaxError`] if the value is missing
+/// or cannot be parsed.
+pub fn parse_value<T: FromStr>(
+    executable: &str,
+    option: CliOption,
+    value: Option<String>,
+) -> Result<T, InvalidSyntaxError> {
+    let name = OPTIONS
+        .iter()
+        .find(|spec| spec.option == option)
+        .map_or_else(String::new, |spec| format!("--{}", spec.long));
+
+    let value = value
+        .ok_or_else(|| InvalidSyntaxError::MissingValue(executable.to_owned(), name.clone()))?;
+
+    value
+        .parse()
+        .map_err(|_| InvalidSyntaxError::InvalidValue(executable.to_owned(), name, value))
+}
+
+/// Generate the help printed with `--help`.
+pub fn help(executable: &str, env_vars: &[(&str, &str)]) -> String {
+    let mut help = format!(
+        "{} {}\n{}\n\nUsage: {executable} [OPTIONS] PATTERN [PATH...]\n\
+        \x20      {executable} [OPTIONS] -e PATTERN [PATH...]\n\n\
+        Arguments:\n\
+        \x20 PATTERN  The searched pattern.\n\
+        \x20 PATH     A file or a directory searched recursively. The standard input is\n\
+        \x20          read if no path or '-' is given.\n\nOptions:\n",
+        env!("CARGO_PKG_NAME"),
+        env!("CARGO_PKG_VERSION"),
+        env!("CARGO_PKG_DESCRIPTION"),
+    );
+
+    let names: Vec<_> = OPTIONS
+        .iter()
+        .map(|spec| {
+            let short = spec
+                .short
+                .map_or_else(|| "    ".to_owned(), |short| format!("-{short}, "));
+
+            match spec.value_name {
+                Some(value_name) if !spec.choices.is_empty() => {
+                    format!("{short}--{}[={value_name}]", spec.long)
+                }
+                Some(value_name) => format!("{short}--{}={value_name}", spec.long),
+                None => format!("{short}--{}", spec.long),
+            }
+        })
+        .collect();
+
+    let width = names.iter().map(String::len).max().unwrap_or_default();
+    for (name, spec) in names.iter().zip(OPTIONS) {
+        let _ = writeln!(help, "  {name:width$}  {}", spec.help);
+    }
+
+    if !env_vars.is_empty() {
+        help.push_str("\nEnvironment variables:\n");
+
+        let width = env_vars
+            .iter()
+            .map(|(name, _)| name.len())
+            .max()
+            .unwrap_or_default();
+        for (name, description) in env_vars {
+            let _ = writeln!(help, "  {name:width$}  {description}");
+        }
+    }
+
+    help
+}
+
+/// Generate the version printed with `--version`.
+pub fn version() -> String {
+    format!("{} {}", env!("CARGO_PKG_NAME"), env!("CARGO_PKG_VERSION"))
+}
+
+/// Find the long option closest to the unknown long option name.
+///
+/// # Returns
+///
+/// Returns the closest option, or [`None`] if all options are too far.
+#[doc(hidden)]
+fn suggest(unknown: &str) -> Option<&'static OptionSpec> {
+    OPTIONS
+        .iter()
+        .map(|spec| (levenshtein(unknown, spec.long), spec))
+        .filter(|(distance, spec)| *distance <= spec.long.len().div_ceil(3))
+        .min_by_key(|(distance, _)| *distance)
+        .map(|(_, spec)| spec)
+}
+
+/// Compute the Levenshtein distance between two texts.
+#[doc(hidden)]
+fn levenshtein(left: &str, right: &str) -> usize {
+    let right: Vec<_> = right.chars().collect();
+    let mut previous: Vec<_> = (0..=right.len()).collect();
+
+    for (i, left_char) in left.chars().enumerate() {
+        let mut current = vec![i + 1; right.len() + 1];
+
+        for (j, right_char) in right.iter().enumerate() {
+            let substitution = previous[j] + usize::from(left_char != *right_char);
+            current[j + 1] = substitution.min(previous[j + 1] + 1).min(current[j] + 1);
+        }
+
+        previous = current;
+    }
+
+    previous[right.len()]
+}
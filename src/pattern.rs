@@ -0,0 +1,213 @@
+use std::ops::Range;
+use std::sync::Arc;
+
+use regex::{Regex, RegexBuilder};
+
+use super::errors::InvalidArgumentError;
+use super::folding::{self, Folded};
+use super::matcher::Matcher;
+use super::matching::Submatch;
+
+/// Indicate how MiniGrep interprets the searched pattern.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum PatternSyntax {
+    /// The pattern is searched as a plain text.
+    #[default]
+    Literal,
+    /// The pattern is a regular expression, with character classes, anchors,
+    /// alternation, repetitions and capture groups.
+    Regex,
+}
+
+impl From<bool> for PatternSyntax {
+    fn from(value: bool) -> Self {
+        if value {
+            Self::Regex
+        } else {
+            Self::Literal
+        }
+    }
+}
+
+/// Build the [`Matcher`] of the pattern, according to its syntax.
+///
+/// # Returns
+///
+/// Returns a [`LiteralMatcher`] or a [`RegexMatcher`], or an
+/// [`InvalidArgumentError::InvalidRegex`] if the pattern is not a valid regular
+/// expression.
+pub(crate) fn build(
+    pattern: &str,
+    syntax: PatternSyntax,
+    case_sensitive: bool,
+) -> Result<Arc<dyn Matcher>, InvalidArgumentError> {
+    Ok(match syntax {
+        PatternSyntax::Literal => Arc::new(LiteralMatcher::new(pattern, case_sensitive)),
+        PatternSyntax::Regex => Arc::new(RegexMatcher::new(pattern, case_sensitive)?),
+    })
+}
+
+/// The [`Matcher`] of a plain text pattern.
+///
+/// A case-insensitive search compares the texts with the full case folding of
+/// Unicode, so `Straße` matches `STRASSE` and `ΟΔΟΣ` matches `οδος`. An occurrence
+/// starts and ends at the boundaries of the characters of the line, and its range
+/// is the one of the original line. An ASCII line is compared without folding it,
+/// and another line is folded once to be selected and to find its matched texts.
+///
+/// # Examples
+///
+/// ```rust
+/// use mini_grep::{LiteralMatcher, Matcher};
+///
+/// let matcher = LiteralMatcher::new("rust", false);
+///
+/// assert!(matcher.is_match("Rust is fast."));
+/// assert_eq!(matcher.find_all("Rust and rustc"), [0..4, 9..13]);
+///
+/// let matcher = LiteralMatcher::new("STRASSE", false);
+///
+/// assert_eq!(matcher.find_all("Die Straße."), [4..11]);
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct LiteralMatcher {
+    /// The searched text, already folded if the search is case-insensitive.
+    #[doc(hidden)]
+    text: String,
+    #[doc(hidden)]
+    case_sensitive: bool,
+}
+
+impl LiteralMatcher {
+    /// Build a [`LiteralMatcher`] of the text, searched case-sensitively or not.
+    pub fn new(text: &str, case_sensitive: bool) -> LiteralMatcher {
+        Self {
+            text: if case_sensitive {
+                text.to_owned()
+            } else {
+                text.chars().flat_map(folding::fold_char).collect()
+            },
+            case_sensitive,
+        }
+    }
+
+    /// Indicate that the line is searched case-insensitively without folding it,
+    /// because it is ASCII.
+    #[doc(hidden)]
+    fn is_ascii_search(&self, line: &str) -> bool {
+        !self.text.is_empty() && line.is_ascii()
+    }
+}
+
+impl Matcher for LiteralMatcher {
+    fn find(&self, line: &str) -> Option<Range<usize>> {
+        if self.case_sensitive {
+            line.find(self.text.as_str())
+                .map(|start| start..start + self.text.len())
+        } else if self.is_ascii_search(line) {
+            folding::find_ascii_iter(line, &self.text).next()
+        } else {
+            Folded::with(line, |folded| folded.find_iter(&self.text).next())
+        }
+    }
+
+    fn is_match(&self, line: &str) -> bool {
+        if self.case_sensitive {
+            line.contains(self.text.as_str())
+        } else {
+            self.find(line).is_some()
+        }
+    }
+
+    /// Find all non-overlapping occurrences of the text in the line.
+    ///
+    /// # Returns
+    ///
+    /// Returns the byte ranges of all occurrences in the line, in order. With a
+    /// case-insensitive search, the ranges point to the original text of the line,
+    /// and not to its folded text.
+    fn find_all(&self, line: &str) -> Vec<Range<usize>> {
+        if self.case_sensitive {
+            line.match_indices(self.text.as_str())
+                .map(|(start, found)| start..start + found.len())
+                .collect()
+        } else if self.is_ascii_search(line) {
+            folding::find_ascii_iter(line, &self.text).collect()
+        } else {
+            Folded::with(line, |folded| folded.find_iter(&self.text).collect())
+        }
+    }
+}
+
+/// The [`Matcher`] of a regular expression, with its capture groups.
+///
+/// # Examples
+///
+/// ```rust
+/// use mini_grep::{Matcher, RegexMatcher};
+///
+/// let matcher = RegexMatcher::new(r"(\w+)@(\w+)", true).unwrap();
+/// let submatches = matcher.find_submatches("mail me@home");
+///
+/// assert_eq!(submatches[0].range, 5..12);
+/// assert_eq!(submatches[0].groups, [Some(5..7), Some(8..12)]);
+/// ```
+#[derive(Debug, Clone)]
+pub struct RegexMatcher {
+    #[doc(hidden)]
+    regex: Regex,
+}
+
+impl RegexMatcher {
+    /// Build a [`RegexMatcher`] of the regular expression, searched
+    /// case-sensitively or not. A case-insensitive search uses the simple case
+    /// folding of Unicode, so `ς` matches `Σ`, but `ß` does not match `SS`.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`RegexMatcher`], or an
+    /// [`InvalidArgumentError::InvalidRegex`] if the pattern is not a valid regular
+    /// expression.
+    pub fn new(pattern: &str, case_sensitive: bool) -> Result<RegexMatcher, InvalidArgumentError> {
+        RegexBuilder::new(pattern)
+            .case_insensitive(!case_sensitive)
+            .build()
+            .map(|regex| Self { regex })
+            .map_err(|error| InvalidArgumentError::InvalidRegex(pattern.to_owned(), error))
+    }
+}
+
+impl Matcher for RegexMatcher {
+    fn find(&self, line: &str) -> Option<Range<usize>> {
+        self.regex.find(line).map(|found| found.range())
+    }
+
+    fn is_match(&self, line: &str) -> bool {
+        self.regex.is_match(line)
+    }
+
+    fn find_all(&self, line: &str) -> Vec<Range<usize>> {
+        self.regex
+            .find_iter(line)
+            .map(|found| found.range())
+            .collect()
+    }
+
+    fn find_submatches(&self, line: &str) -> Vec<Submatch> {
+        self.regex
+            .captures_iter(line)
+            .filter_map(|captures| {
+                // The first group is always the whole matched text.
+                let mut groups = captures.iter();
+                let range = groups.next().flatten()?.range();
+
+                Some(Submatch {
+                    range,
+                    groups: groups
+                        .map(|group| group.map(|group| group.range()))
+                        .collect(),
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,691 @@
+use std::io::{self, Write};
+use std::path::Path;
+
+use super::color::Colors;
+use super::command::{OutputFormat, Report};
+use super::context::LineKind;
+use super::errors::{InvalidArgumentError, MiniGrepArgsError};
+use super::input;
+use super::json;
+use super::matching::{ColumnUnit, InvalidUtf8, Match, Submatch};
+use super::sink::Sink;
+
+/// Print to the writer lines yielded by a search, with the [`OutputFormat`] of the
+/// [`Command`](super::Command).
+///
+/// A selected line is printed like `line_no:line`, and a context line like
+/// `line_no-line`, with the column and the byte offset if they are asked.
+/// Non-adjacent groups of lines are separated by [`Printer::SEPARATOR`], if context
+/// lines are printed. Paths, line numbers, separators and matched text are colored
+/// with the [`Colors`], if any.
+///
+/// The lines of a binary file are not printed: only a message like
+/// `Binary file path matches` is printed at its first selected line, and the search
+/// of the file stops.
+///
+/// The [`OutputFormat::Json`] is printed by the [`JsonPrinter`].
+#[derive(Debug)]
+pub struct Printer<W> {
+    #[doc(hidden)]
+    writer: W,
+    #[doc(hidden)]
+    format: OutputFormat,
+    #[doc(hidden)]
+    with_filename: bool,
+    #[doc(hidden)]
+    with_separator: bool,
+    #[doc(hidden)]
+    colors: Option<Colors>,
+    #[doc(hidden)]
+    column: Option<ColumnUnit>,
+    #[doc(hidden)]
+    with_byte_offset: bool,
+    #[doc(hidden)]
+    invalid_utf8: InvalidUtf8,
+    #[doc(hidden)]
+    printed: bool,
+    #[doc(hidden)]
+    last_line_no: Option<usize>,
+    #[doc(hidden)]
+    binary: bool,
+    #[doc(hidden)]
+    binary_reported: bool,
+}
+
+impl<W: Write> Printer<W> {
+    /// The line printed between non-adjacent groups of lines.
+    pub const SEPARATOR: &'static str = "--";
+
+    /// Build a [`Printer`] to the writer.
+    ///
+    /// Each line is prefixed with the path of its file if `with_filename` is
+    /// `true`, the column of its first matched text if `column` is not [`None`],
+    /// and its byte offset in the file if `with_byte_offset` is `true`. Groups are
+    /// separated if `with_separator` is `true`, and the output is colored if
+    /// `colors` is not [`None`].
+    ///
+    /// With the [`OutputFormat::Vimgrep`], each line is always prefixed with the
+    /// path of its file and the column of its matched text, counted with the
+    /// [`ColumnUnit::Bytes`] if `column` is [`None`].
+    pub fn new(
+        writer: W,
+        format: OutputFormat,
+        with_filename: bool,
+        with_separator: bool,
+        colors: Option<Colors>,
+        column: Option<ColumnUnit>,
+        with_byte_offset: bool,
+    ) -> Printer<W> {
+        let column = match format {
+            OutputFormat::Vimgrep => Some(column.unwrap_or_default()),
+            _ => column,
+        };
+
+        Self {
+            writer,
+            format,
+            with_filename: with_filename || format == OutputFormat::Vimgrep,
+            with_separator: with_separator && format != OutputFormat::Vimgrep,
+            colors,
+            column,
+            with_byte_offset,
+            invalid_utf8: InvalidUtf8::default(),
+            printed: false,
+            last_line_no: None,
+            binary: false,
+            binary_reported: false,
+        }
+    }
+
+    /// Print the bytes of lines that are not valid UTF-8 with the [`InvalidUtf8`]
+    /// mode, in place of [`InvalidUtf8::Lossy`].
+    pub fn with_invalid_utf8(mut self, invalid_utf8: InvalidUtf8) -> Printer<W> {
+        self.invalid_utf8 = invalid_utf8;
+        self
+    }
+
+    /// Print the line, and the separator if the line is not adjacent to the last
+    /// printed line. The matched text is colored, if the output is colored.
+    ///
+    /// With the [`OutputFormat::Vimgrep`], the selected line is printed once per
+    /// matched text, with the column of the matched text, and a context line is not
+    /// printed. A line selected by an inverted match has no matched text, and is
+    /// printed once with the column 1.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if the line cannot be written.
+    pub fn print(&mut self, filename: &Path, found: &Match) -> io::Result<()> {
+        if self.print_binary(filename, found)? {
+            return Ok(());
+        }
+
+        if self.format == OutputFormat::Vimgrep {
+            if found.kind == LineKind::Match {
+                let text = self.paint_line(found);
+                if found.submatches.is_empty() {
+                    self.print_prefixed(filename, found, 0, found.line_range.start, &text)?;
+                }
+                for submatch in &found.submatches {
+                    let start = submatch.range.start;
+                    let offset = found.line_range.start + start;
+                    self.print_prefixed(filename, found, start, offset, &text)?;
+                }
+            }
+
+            return Ok(());
+        }
+
+        let is_adjacent = self
+            .last_line_no
+            .is_some_and(|last| last + 1 == found.line_number);
+        if self.with_separator && self.printed && !is_adjacent {
+            let separator = self.paint_separator(Self::SEPARATOR);
+            writeln!(self.writer, "{separator}")?;
+        }
+
+        self.printed = true;
+        self.last_line_no = Some(found.line_number);
+
+        let start = found
+            .submatches
+            .first()
+            .map_or(0, |submatch| submatch.range.start);
+        let text = self.paint_line(found);
+        self.print_prefixed(filename, found, start, found.line_range.start, &text)
+    }
+
+    /// Print the matched text of the submatch, like `line_no:column:text`. An empty
+    /// matched text is not printed.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if the text cannot be written.
+    pub fn print_fragment(
+        &mut self,
+        filename: &Path,
+        found: &Match,
+        submatch: &Submatch,
+    ) -> io::Result<()> {
+        if submatch.range.is_empty() {
+            return Ok(());
+        }
+
+        let text = self.invalid_utf8.decode(found.text_bytes(submatch));
+        let fragment = match &self.colors {
+            Some(colors) => Colors::paint(&colors.selected_match, &text),
+            None => text.into_owned(),
+        };
+        self.print_prefixed(
+            filename,
+            found,
+            submatch.range.start,
+            found.line_range.start + submatch.range.start,
+            &fragment,
+        )
+    }
+
+    /// Print each matched text of the line, if it is a selected line.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if a text cannot be written.
+    pub fn print_fragments(&mut self, filename: &Path, found: &Match) -> io::Result<()> {
+        if self.print_binary(filename, found)? {
+            return Ok(());
+        }
+
+        if found.kind == LineKind::Match {
+            for submatch in &found.submatches {
+                self.print_fragment(filename, found, submatch)?;
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Print the number of selected lines of a file, prefixed with the path of the
+    /// file if `with_filename` is `true`.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if the number cannot be written.
+    pub fn print_count(&mut self, filename: &Path, count: usize) -> io::Result<()> {
+        if self.with_filename {
+            let filename = self.paint_filename(filename);
+            let separator = self.paint_separator(":");
+            self.writer.write_all(&filename)?;
+            writeln!(self.writer, "{separator}{count}")
+        } else {
+            writeln!(self.writer, "{count}")
+        }
+    }
+
+    /// Print the path of a file.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if the path cannot be written.
+    pub fn print_filename(&mut self, filename: &Path) -> io::Result<()> {
+        let filename = self.paint_filename(filename);
+        self.writer.write_all(&filename)?;
+        writeln!(self.writer)
+    }
+
+    /// Print a text without prefix, like a banner.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`io::Error`] if the text cannot be written.
+    pub fn print_text(&mut self, text: &str) -> io::Result<()> {
+        writeln!(self.writer, "{text}")
+    }
+
+    /// Print the message of the binary file at its first selected line, in place of
+    /// the lines of the file.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` if the file is binary, and the line must not be printed, or an
+    /// [`io::Error`] if the message cannot be written.
+    #[doc(hidden)]
+    fn print_binary(&mut self, filename: &Path, found: &Match) -> io::Result<bool> {
+        if !self.binary {
+            return Ok(false);
+        }
+
+        if found.kind == LineKind::Match && !self.binary_reported {
+            self.binary_reported = true;
+
+            let filename = self.paint_filename(filename);
+            write!(self.writer, "Binary file ")?;
+            self.writer.write_all(&filename)?;
+            writeln!(self.writer, " matches")?;
+        }
+
+        Ok(true)
+    }
+
+    /// Indicate that the rest of the file is not printed, because the file is
+    /// binary and its message is printed.
+    #[doc(hidden)]
+    fn is_file_done(&self) -> bool {
+        self.binary_reported
+    }
+
+    /// Print the text with its prefix, like
+    /// `path:line_no:column:byte_offset:text`. The path is printed if
+    /// `with_filename` is `true`, the column if a [`ColumnUnit`] is given, and the
+    /// byte offset if `with_byte_offset` is `true`.
+    #[doc(hidden)]
+    fn print_prefixed(
+        &mut self,
+        filename: &Path,
+        found: &Match,
+        start: usize,
+        byte_offset: usize,
+        text: &str,
+    ) -> io::Result<()> {
+        let separator = self.paint_separator(match found.kind {
+            LineKind::Match => ":",
+            LineKind::Context => "-",
+        });
+
+        if self.with_filename {
+            let filename = self.paint_filename(filename);
+            self.writer.write_all(&filename)?;
+            self.writer.write_all(separator.as_bytes())?;
+        }
+
+        let mut prefix = String::new();
+
+        prefix.push_str(&self.paint_line_no(found.line_number));
+        prefix.push_str(&separator);
+
+        if let Some(unit) = self.column {
+            prefix.push_str(&self.paint_line_no(found.column(start, unit)));
+            prefix.push_str(&separator);
+        }
+
+        if self.with_byte_offset {
+            prefix.push_str(&self.paint_line_no(byte_offset));
+            prefix.push_str(&separator);
+        }
+
+        if self.format == OutputFormat::Banner {
+            prefix.push(' ');
+        }
+
+        writeln!(self.writer, "{prefix}{text}")
+    }
+
+    /// Color the line, and its matched text, if the output is colored.
+    #[doc(hidden)]
+    fn paint_line(&self, found: &Match) -> String {
+        match &self.colors {
+            Some(colors) => colors.paint_line(
+                found.kind,
+                &found.bytes,
+                &found.submatches,
+                self.invalid_utf8,
+            ),
+            None => self.invalid_utf8.decode(&found.bytes).into_owned(),
+        }
+    }
+
+    /// Color a line number, a column or a byte offset, if the output is colored.
+    #[doc(hidden)]
+    fn paint_line_no(&self, number: usize) -> String {
+        match &self.colors {
+            Some(colors) => Colors::paint(&colors.line_no, &number.to_string()),
+            None => number.to_string(),
+        }
+    }
+
+    /// Color the path of a file, if the output is colored. The path is kept as
+    /// bytes, because it may not be valid Unicode.
+    #[doc(hidden)]
+    fn paint_filename(&self, filename: &Path) -> Vec<u8> {
+        let filename = input::path_bytes(filename);
+        match &self.colors {
+            Some(colors) => Colors::paint_bytes(&colors.filename, &filename),
+            None => filename.into_owned(),
+        }
+    }
+
+    /// Color a separator, if the output is colored.
+    #[doc(hidden)]
+    fn paint_separator(&self, separator: &str) -> String {
+        match &self.colors {
+            Some(colors) => Colors::paint(&colors.separator, separator),
+            None => separator.to_owned(),
+        }
+    }
+}
+
+impl<W: Write> Sink for Printer<W> {
+    fn begin_file(&mut self, _path: &Path) -> io::Result<()> {
+        self.last_line_no = None;
+        self.binary = false;
+        self.binary_reported = false;
+        Ok(())
+    }
+
+    fn binary_file(&mut self, _path: &Path) -> io::Result<()> {
+        self.binary = true;
+        Ok(())
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        self.print(path, &found)?;
+        Ok(!self.is_file_done())
+    }
+
+    fn context(&mut self, path: &Path, line: Match) -> io::Result<()> {
+        self.print(path, &line)
+    }
+}
+
+/// Print with the [`Printer`] only each matched text of selected lines, and nothing
+/// for context lines.
+#[derive(Debug)]
+pub struct OnlyMatchingPrinter<W> {
+    #[doc(hidden)]
+    printer: Printer<W>,
+}
+
+impl<W: Write> OnlyMatchingPrinter<W> {
+    /// Build an [`OnlyMatchingPrinter`] with the printer.
+    pub fn new(printer: Printer<W>) -> OnlyMatchingPrinter<W> {
+        Self { printer }
+    }
+}
+
+impl<W: Write> Sink for OnlyMatchingPrinter<W> {
+    fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+        self.printer.begin_file(path)
+    }
+
+    fn binary_file(&mut self, path: &Path) -> io::Result<()> {
+        self.printer.binary_file(path)
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        self.printer.print_fragments(path, &found)?;
+        Ok(!self.printer.is_file_done())
+    }
+}
+
+/// Print with the [`Printer`] found lines, after a sentence introducing them, or a
+/// sentence telling that no line is found, with the [`OutputFormat::Banner`].
+#[derive(Debug)]
+pub struct BannerPrinter<W> {
+    #[doc(hidden)]
+    printer: Printer<W>,
+    #[doc(hidden)]
+    only_matching: bool,
+    #[doc(hidden)]
+    input: Option<String>,
+    #[doc(hidden)]
+    pattern: String,
+    #[doc(hidden)]
+    found: bool,
+    #[doc(hidden)]
+    searched: bool,
+}
+
+impl<W: Write> BannerPrinter<W> {
+    /// Build a [`BannerPrinter`] with the printer.
+    ///
+    /// The sentences name the `input` if only one file is searched, like
+    /// `file 'example.txt'`, and describe the searched `pattern`, like
+    /// `with the case sensitive pattern 'Rust'`. Only each matched text of selected
+    /// lines is printed, if `only_matching` is `true`. No sentence is printed if no
+    /// file is searched, like a file that cannot be opened.
+    pub fn new(
+        printer: Printer<W>,
+        only_matching: bool,
+        input: Option<String>,
+        pattern: String,
+    ) -> BannerPrinter<W> {
+        Self {
+            printer,
+            only_matching,
+            input,
+            pattern,
+            found: false,
+            searched: false,
+        }
+    }
+
+    /// Print the line, or only its matched texts, after the sentence introducing
+    /// found lines if it is the first found line.
+    #[doc(hidden)]
+    fn print(&mut self, path: &Path, found: &Match) -> io::Result<()> {
+        if !self.found {
+            self.found = true;
+
+            let banner = match &self.input {
+                Some(input) => format!("The {input} contains these lines {}:", self.pattern),
+                None => format!("The searched files contain these lines {}:", self.pattern),
+            };
+            self.printer.print_text(&banner)?;
+        }
+
+        if self.only_matching {
+            self.printer.print_fragments(path, found)
+        } else {
+            self.printer.print(path, found)
+        }
+    }
+}
+
+impl<W: Write> Sink for BannerPrinter<W> {
+    fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+        self.searched = true;
+        self.printer.begin_file(path)
+    }
+
+    fn binary_file(&mut self, path: &Path) -> io::Result<()> {
+        self.printer.binary_file(path)
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        self.print(path, &found)?;
+        Ok(!self.printer.is_file_done())
+    }
+
+    fn context(&mut self, path: &Path, line: Match) -> io::Result<()> {
+        self.print(path, &line)
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        if self.found || !self.searched {
+            return Ok(());
+        }
+
+        let banner = match &self.input {
+            Some(input) => format!("The {input} does not contain any line {}.", self.pattern),
+            None => format!(
+                "The searched files do not contain any line {}.",
+                self.pattern
+            ),
+        };
+        self.printer.print_text(&banner)
+    }
+}
+
+/// Print with the [`Printer`] a [`Report`] summarizing each file, in place of its
+/// found lines.
+///
+/// The search of a file stops at its first selected line, if only the paths of the
+/// files with or without a selected line are printed.
+#[derive(Debug)]
+pub struct SummaryPrinter<W> {
+    #[doc(hidden)]
+    printer: Printer<W>,
+    #[doc(hidden)]
+    report: Report,
+    #[doc(hidden)]
+    count: usize,
+}
+
+impl<W: Write> SummaryPrinter<W> {
+    /// Build a [`SummaryPrinter`] with the printer.
+    pub fn new(printer: Printer<W>, report: Report) -> SummaryPrinter<W> {
+        Self {
+            printer,
+            report,
+            count: 0,
+        }
+    }
+}
+
+impl<W: Write> Sink for SummaryPrinter<W> {
+    fn begin_file(&mut self, _path: &Path) -> io::Result<()> {
+        self.count = 0;
+        Ok(())
+    }
+
+    fn matched(&mut self, _path: &Path, _found: Match) -> io::Result<bool> {
+        self.count += 1;
+        Ok(self.report == Report::Count)
+    }
+
+    fn end_file(&mut self, path: &Path) -> io::Result<()> {
+        match self.report {
+            Report::Count => self.printer.print_count(path, self.count),
+            Report::FilesWithMatches if self.count > 0 => self.printer.print_filename(path),
+            Report::FilesWithoutMatch if self.count == 0 => self.printer.print_filename(path),
+            Report::FilesWithMatches | Report::FilesWithoutMatch => Ok(()),
+            Report::Lines => unreachable!("Lines are not a summary."),
+        }
+    }
+}
+
+/// Print to the writer lines yielded by a search as JSON Lines, with the
+/// [`OutputFormat::Json`].
+///
+/// Each line is printed as a JSON object, between the objects of the begin and the
+/// end of its file, each file or directory that cannot be read is printed as an
+/// error object, and a summary is printed at the end of the search.
+#[derive(Debug)]
+pub struct JsonPrinter<W> {
+    #[doc(hidden)]
+    writer: W,
+    #[doc(hidden)]
+    stats: Stats,
+}
+
+/// The statistics of a search, printed with the [`OutputFormat::Json`].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+#[doc(hidden)]
+struct Stats {
+    #[doc(hidden)]
+    searched_files: usize,
+    #[doc(hidden)]
+    matched_files: usize,
+    #[doc(hidden)]
+    matched_lines: usize,
+    #[doc(hidden)]
+    file_matched_lines: usize,
+}
+
+impl<W: Write> JsonPrinter<W> {
+    /// Build a [`JsonPrinter`] to the writer.
+    pub fn new(writer: W) -> JsonPrinter<W> {
+        Self {
+            writer,
+            stats: Stats::default(),
+        }
+    }
+
+    /// Print the line as a JSON object, with its matched text.
+    #[doc(hidden)]
+    fn print(&mut self, filename: &Path, found: &Match) -> io::Result<()> {
+        let kind = match found.kind {
+            LineKind::Match => "match",
+            LineKind::Context => "context",
+        };
+        let submatches = found
+            .submatches
+            .iter()
+            .map(|submatch| {
+                format!(
+                    "{{\"match\":{},\"start\":{},\"end\":{}}}",
+                    json::data(found.text_bytes(submatch)),
+                    submatch.range.start,
+                    submatch.range.end,
+                )
+            })
+            .collect::<Vec<_>>()
+            .join(",");
+
+        writeln!(
+            self.writer,
+            "{{\"type\":\"{kind}\",\"data\":{{\"path\":{},\"lines\":{},\"line_number\":{},\
+            \"absolute_offset\":{},\"submatches\":[{submatches}]}}}}",
+            json::data(&input::path_bytes(filename)),
+            json::data(&found.bytes),
+            found.line_number,
+            found.line_range.start,
+        )
+    }
+}
+
+impl<W: Write> Sink for JsonPrinter<W> {
+    fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+        self.stats.searched_files += 1;
+        self.stats.file_matched_lines = 0;
+
+        writeln!(
+            self.writer,
+            "{{\"type\":\"begin\",\"data\":{{\"path\":{}}}}}",
+            json::data(&input::path_bytes(path)),
+        )
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        self.stats.matched_lines += 1;
+        self.stats.file_matched_lines += 1;
+
+        self.print(path, &found)?;
+        Ok(true)
+    }
+
+    fn context(&mut self, path: &Path, line: Match) -> io::Result<()> {
+        self.print(path, &line)
+    }
+
+    fn open_error(&mut self, path: &Path, error: InvalidArgumentError) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "{{\"type\":\"error\",\"data\":{{\"path\":{},\"code\":{},\"message\":{}}}}}",
+            json::data(&input::path_bytes(path)),
+            error.code(),
+            json::string(&error.to_string()),
+        )
+    }
+
+    fn end_file(&mut self, path: &Path) -> io::Result<()> {
+        if self.stats.file_matched_lines > 0 {
+            self.stats.matched_files += 1;
+        }
+
+        writeln!(
+            self.writer,
+            "{{\"type\":\"end\",\"data\":{{\"path\":{},\"stats\":{{\"matched_lines\":{}}}}}}}",
+            json::data(&input::path_bytes(path)),
+            self.stats.file_matched_lines,
+        )
+    }
+
+    fn finish(&mut self) -> io::Result<()> {
+        writeln!(
+            self.writer,
+            "{{\"type\":\"summary\",\"data\":{{\"stats\":{{\"searched_files\":{},\
+            \"matched_files\":{},\"matched_lines\":{}}}}}}}",
+            self.stats.searched_files, self.stats.matched_files, self.stats.matched_lines,
+        )
+    }
+}
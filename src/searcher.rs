@@ -0,0 +1,489 @@
+use std::borrow::Cow;
+use std::io::{self, BufRead, BufReader, Read};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+use super::binary::BinaryPolicy;
+use super::context::{Context, LineKind};
+use super::encoding::{Decoder, Encoding};
+use super::errors::{InvalidArgumentError, ReadError};
+use super::input::Input;
+use super::line::{Line, Lines};
+use super::matcher::Matcher;
+use super::matching::{Match, Submatch};
+use super::pattern::{self, PatternSyntax};
+use super::sink::Sink;
+
+/// The search engine of MiniGrep, searching a pattern in each line of a reader, a
+/// string, a file or a directory.
+///
+/// # Examples
+///
+/// ```rust
+/// use mini_grep::{LineKind, PatternSyntax, Searcher};
+///
+/// let searcher = Searcher::builder(r"(\w+) is")
+///     .syntax(PatternSyntax::Regex)
+///     .after_context(1)
+///     .build()
+///     .unwrap();
+///
+/// let lines: Vec<_> = searcher
+///     .search_str("Rust is fast.\nIt is safe.\nThe end.\n")
+///     .collect();
+///
+/// assert_eq!(lines.len(), 3);
+/// assert_eq!(lines[0].kind, LineKind::Match);
+/// assert_eq!(lines[0].submatches[0].range, 0..7);
+/// assert_eq!(lines[0].submatches[0].groups, [Some(0..4)]);
+/// assert_eq!(lines[2].kind, LineKind::Context);
+/// ```
+#[derive(Debug, Clone)]
+pub struct Searcher {
+    #[doc(hidden)]
+    pattern: String,
+    #[doc(hidden)]
+    matcher: Arc<dyn Matcher>,
+    #[doc(hidden)]
+    case_sensitive: bool,
+    #[doc(hidden)]
+    invert_match: bool,
+    #[doc(hidden)]
+    before_context: usize,
+    #[doc(hidden)]
+    after_context: usize,
+    #[doc(hidden)]
+    binary: BinaryPolicy,
+    #[doc(hidden)]
+    encoding: Encoding,
+}
+
+/// A file searched by [`Searcher::search_path()`], with all its lines yielded by
+/// the search.
+#[derive(Debug)]
+pub struct SearchedFile {
+    /// The path to the file, or `(standard input)`. It may not be valid Unicode.
+    pub path: PathBuf,
+    /// All lines yielded by the search, or a [`ReadError`] for each line that
+    /// cannot be read.
+    pub lines: Vec<Result<Match, ReadError>>,
+    /// The error of the file that cannot be opened, or of the directory that cannot
+    /// be read, in place of its lines.
+    pub error: Option<InvalidArgumentError>,
+}
+
+/// The builder of a [`Searcher`], with the options of the search.
+///
+/// By default, the pattern is a case-sensitive plain text, the match is not
+/// inverted, no context line is yielded, a binary file is reported with
+/// [`BinaryPolicy::Report`], and the encoding is detected with [`Encoding::Auto`].
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct SearcherBuilder {
+    #[doc(hidden)]
+    pattern: String,
+    #[doc(hidden)]
+    syntax: PatternSyntax,
+    #[doc(hidden)]
+    case_sensitive: bool,
+    #[doc(hidden)]
+    invert_match: bool,
+    #[doc(hidden)]
+    before_context: usize,
+    #[doc(hidden)]
+    after_context: usize,
+    #[doc(hidden)]
+    binary: BinaryPolicy,
+    #[doc(hidden)]
+    encoding: Encoding,
+}
+
+impl SearcherBuilder {
+    /// Build a [`SearcherBuilder`] of the pattern, with the default options.
+    pub fn new(pattern: impl Into<String>) -> SearcherBuilder {
+        Self {
+            pattern: pattern.into(),
+            syntax: PatternSyntax::default(),
+            case_sensitive: true,
+            invert_match: false,
+            before_context: 0,
+            after_context: 0,
+            binary: BinaryPolicy::default(),
+            encoding: Encoding::default(),
+        }
+    }
+
+    /// Interpret the pattern as a plain text or as a regular expression.
+    pub fn syntax(mut self, syntax: PatternSyntax) -> SearcherBuilder {
+        self.syntax = syntax;
+        self
+    }
+
+    /// Search the pattern case-sensitively or not.
+    pub fn case_sensitive(mut self, case_sensitive: bool) -> SearcherBuilder {
+        self.case_sensitive = case_sensitive;
+        self
+    }
+
+    /// Select lines not containing the pattern, if `invert_match` is `true`.
+    pub fn invert_match(mut self, invert_match: bool) -> SearcherBuilder {
+        self.invert_match = invert_match;
+        self
+    }
+
+    /// Yield the given number of context lines before each selected line.
+    pub fn before_context(mut self, lines: usize) -> SearcherBuilder {
+        self.before_context = lines;
+        self
+    }
+
+    /// Yield the given number of context lines after each selected line.
+    pub fn after_context(mut self, lines: usize) -> SearcherBuilder {
+        self.after_context = lines;
+        self
+    }
+
+    /// Yield the given number of context lines before and after each selected line.
+    pub fn context(self, lines: usize) -> SearcherBuilder {
+        self.before_context(lines).after_context(lines)
+    }
+
+    /// Search a binary file with the policy, in [`Searcher::search_to()`].
+    pub fn binary(mut self, binary: BinaryPolicy) -> SearcherBuilder {
+        self.binary = binary;
+        self
+    }
+
+    /// Decode each reader with the encoding, in place of the detection of its
+    /// encoding with its BOM.
+    ///
+    /// The byte offsets of the lines and of the matched texts are the ones of the
+    /// text transcoded to UTF-8, without its BOM.
+    pub fn encoding(mut self, encoding: Encoding) -> SearcherBuilder {
+        self.encoding = encoding;
+        self
+    }
+
+    /// Build the [`Searcher`].
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`Searcher`], or an [`InvalidArgumentError`] if the
+    /// pattern is blank or is not a valid regular expression.
+    pub fn build(self) -> Result<Searcher, InvalidArgumentError> {
+        if self.pattern.trim().is_empty() {
+            return Err(InvalidArgumentError::BlankPattern(self.pattern));
+        }
+
+        let matcher = pattern::build(&self.pattern, self.syntax, self.case_sensitive)?;
+
+        Ok(self.build_with_arc(matcher))
+    }
+
+    /// Build the [`Searcher`] with a custom [`Matcher`], in place of the matcher of
+    /// the pattern.
+    ///
+    /// The pattern is only the description of the search, and the syntax and the
+    /// case sensitivity are ignored: the matcher alone decides which lines contain
+    /// the pattern.
+    ///
+    /// # Returns
+    ///
+    /// Returns a new instance of [`Searcher`].
+    pub fn build_with(self, matcher: impl Matcher + 'static) -> Searcher {
+        self.build_with_arc(Arc::new(matcher))
+    }
+
+    /// Build the [`Searcher`] with the shared matcher.
+    #[doc(hidden)]
+    fn build_with_arc(self, matcher: Arc<dyn Matcher>) -> Searcher {
+        Searcher {
+            pattern: self.pattern,
+            matcher,
+            case_sensitive: self.case_sensitive,
+            invert_match: self.invert_match,
+            before_context: self.before_context,
+            after_context: self.after_context,
+            binary: self.binary,
+            encoding: self.encoding,
+        }
+    }
+}
+
+impl Searcher {
+    /// Build a [`SearcherBuilder`] of the pattern, with the default options.
+    pub fn builder(pattern: impl Into<String>) -> SearcherBuilder {
+        SearcherBuilder::new(pattern)
+    }
+
+    /// Get the searched pattern, as given to the [`SearcherBuilder`].
+    pub fn pattern(&self) -> &str {
+        &self.pattern
+    }
+
+    /// Indicate that the pattern is searched case-sensitively.
+    pub fn is_case_sensitive(&self) -> bool {
+        self.case_sensitive
+    }
+
+    /// Indicate that lines not containing the pattern are selected.
+    pub fn is_inverted(&self) -> bool {
+        self.invert_match
+    }
+
+    /// Get the [`Matcher`] finding the pattern in each line.
+    pub fn matcher(&self) -> &dyn Matcher {
+        self.matcher.as_ref()
+    }
+
+    /// Get the number of context lines yielded before each selected line.
+    pub fn before_context(&self) -> usize {
+        self.before_context
+    }
+
+    /// Get the number of context lines yielded after each selected line.
+    pub fn after_context(&self) -> usize {
+        self.after_context
+    }
+
+    /// Get the policy of the search of a binary file.
+    pub fn binary(&self) -> BinaryPolicy {
+        self.binary
+    }
+
+    /// Get the encoding of each reader.
+    pub fn encoding(&self) -> Encoding {
+        self.encoding
+    }
+
+    /// Search in the reader all lines containing the pattern, or all lines not
+    /// containing it if the match is inverted, and the context lines around them.
+    ///
+    /// The search is lazy: the reader is read line by line while the returned
+    /// iterator is consumed, so each found line can be used as soon as it is found,
+    /// and the memory usage is bounded by the longest line, and the number of
+    /// context lines.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields a [`Match`] for each selected line and
+    /// each context line, with all matched texts of the pattern in the line, or a
+    /// [`ReadError`] if the reader fails, and the search stops. A line that is not
+    /// valid UTF-8 is searched like the other lines, in its text decoded with
+    /// [`InvalidUtf8::Lossy`](crate::InvalidUtf8::Lossy). The reader is decoded with
+    /// the [`Encoding`], and a binary file is searched as a text file, whatever the
+    /// [`BinaryPolicy`].
+    pub fn search<'a, R: Read + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<Match, ReadError>> + 'a {
+        self.search_buf(BufReader::new(reader))
+    }
+
+    /// Search in the buffered reader, like [`Searcher::search()`], without adding
+    /// another buffer.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields a [`Match`] for each selected line and
+    /// each context line, or a [`ReadError`] if the reader fails.
+    pub fn search_buf<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<Match, ReadError>> + 'a {
+        self.search_decoded(Decoder::new(reader, self.encoding))
+    }
+
+    /// Search in the buffered reader, already decoded to UTF-8.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields a [`Match`] for each selected line and
+    /// each context line, or a [`ReadError`] if the reader fails.
+    #[doc(hidden)]
+    fn search_decoded<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<Match, ReadError>> + 'a {
+        Context::new(self.select(reader), self.before_context, self.after_context).map(|result| {
+            result.map(|(kind, line)| {
+                let submatches = self.find_submatches(&line);
+                Match::new(kind, line, submatches)
+            })
+        })
+    }
+
+    /// Search in the text, like [`Searcher::search()`].
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields a [`Match`] for each selected line and
+    /// each context line.
+    pub fn search_str<'a>(&'a self, text: &'a str) -> impl Iterator<Item = Match> + 'a {
+        self.search_bytes(text.as_bytes())
+    }
+
+    /// Search in the bytes, like [`Searcher::search()`], without copying them. The
+    /// bytes may not be valid UTF-8.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields a [`Match`] for each selected line and
+    /// each context line.
+    pub fn search_bytes<'a>(&'a self, bytes: &'a [u8]) -> impl Iterator<Item = Match> + 'a {
+        // The read of bytes in memory cannot fail.
+        self.search_buf(bytes).filter_map(Result::ok)
+    }
+
+    /// Search in the file, or recursively in the directory, like
+    /// [`Searcher::search()`], and collect each searched file, like
+    /// [`Searcher::search_path_to()`]. The path `-` reads the standard input.
+    ///
+    /// # Returns
+    ///
+    /// Returns each [`SearchedFile`], or an [`InvalidArgumentError`] if the path
+    /// does not exist, is not a file nor a directory, or points to a not readable
+    /// file or directory.
+    ///
+    /// # Read errors
+    ///
+    /// Collect the error of a directory that cannot be read or of a file that
+    /// cannot be opened during the walk in [`SearchedFile::error`], and continue the
+    /// walk.
+    pub fn search_path(
+        &self,
+        path: impl AsRef<Path>,
+    ) -> Result<Vec<SearchedFile>, InvalidArgumentError> {
+        let path = path.as_ref();
+        let mut files = Vec::new();
+
+        self.search_path_to(path, &mut files)
+            .expect("Collecting the files in a vector cannot fail.");
+
+        // Only the error of the path itself is returned, not the errors of its walk.
+        if let [file] = files.as_mut_slice() {
+            if file.path == path && file.error.is_some() {
+                return Err(file.error.take().expect("The error is checked."));
+            }
+        }
+
+        Ok(files)
+    }
+
+    /// Search in the file, or recursively in the directory, like
+    /// [`Searcher::search_path()`], and send each event of the search to the sink,
+    /// like [`Searcher::search_to()`]. The path `-` reads the standard input.
+    ///
+    /// # Returns
+    ///
+    /// Returns the first [`io::Error`] of the sink, and stops the search.
+    ///
+    /// # Read errors
+    ///
+    /// Send to [`Sink::open_error()`] the error of the path, or of a directory that
+    /// cannot be read or a file that cannot be opened during the walk, and continue
+    /// the walk.
+    pub fn search_path_to<S>(&self, path: impl AsRef<Path>, sink: &mut S) -> io::Result<()>
+    where
+        S: Sink + ?Sized,
+    {
+        let input = Input::build(path.as_ref().to_path_buf());
+
+        input.for_each_file(&mut |filename, reader| match reader {
+            Ok(reader) => self.search_to(filename, reader, sink),
+            Err(error) => sink.open_error(filename, error),
+        })
+    }
+
+    /// Search in the reader, like [`Searcher::search()`], and send each event of the
+    /// search to the sink: the begin of the file, each selected line, each context
+    /// line, each line that cannot be read, and the end of the file. The path is
+    /// only given to the sink, and the end of the search is not sent. The read of
+    /// the reader stops at a selected line, if [`Sink::matched()`] returns `false`.
+    ///
+    /// The reader is decoded with the [`Encoding`], then its start is checked with
+    /// [`BinaryPolicy::is_binary()`], unless the policy is [`BinaryPolicy::Text`].
+    /// A binary file is sent without any line with [`BinaryPolicy::Skip`], and is
+    /// sent to [`Sink::binary_file()`] before its lines with
+    /// [`BinaryPolicy::Report`].
+    ///
+    /// # Returns
+    ///
+    /// Returns the first [`io::Error`] of the sink, and stops the search.
+    pub fn search_to<R, S>(&self, path: impl AsRef<Path>, reader: R, sink: &mut S) -> io::Result<()>
+    where
+        R: Read,
+        S: Sink + ?Sized,
+    {
+        let path = path.as_ref();
+        let mut reader = Decoder::new(BufReader::new(reader), self.encoding);
+        sink.begin_file(path)?;
+
+        // An error of the reader is returned again by the search of its lines.
+        if self.binary != BinaryPolicy::Text && reader.fill_buf().is_ok_and(BinaryPolicy::is_binary)
+        {
+            match self.binary {
+                BinaryPolicy::Skip => return sink.end_file(path),
+                BinaryPolicy::Report => sink.binary_file(path)?,
+                BinaryPolicy::Text => unreachable!("A text policy does not check the file."),
+            }
+        }
+
+        for result in self.search_decoded(reader) {
+            match result {
+                Ok(found) if found.kind == LineKind::Match => {
+                    if !sink.matched(path, found)? {
+                        break;
+                    }
+                }
+                Ok(line) => sink.context(path, line)?,
+                Err(error) => sink.read_error(path, error)?,
+            }
+        }
+
+        sink.end_file(path)
+    }
+
+    /// Read lazily each line of the buffered reader, and check if it is selected: if it
+    /// contains the pattern, or if it does not contain it when the match is
+    /// inverted.
+    ///
+    /// # Returns
+    ///
+    /// Returns an [`Iterator`] that yields all lines, with `true` if the line is
+    /// selected, or a [`ReadError`] for each line that cannot be read.
+    #[doc(hidden)]
+    fn select<'a, R: BufRead + 'a>(
+        &'a self,
+        reader: R,
+    ) -> impl Iterator<Item = Result<(Line, bool), ReadError>> + 'a {
+        Lines::new(reader).map(move |(number, offset, bytes)| {
+            let bytes = bytes.map_err(|error| ReadError::Line(number, error))?;
+            let line = Line {
+                number,
+                offset,
+                bytes,
+            };
+            let selected = self.matcher.is_match(&line.text()) != self.invert_match;
+
+            Ok((line, selected))
+        })
+    }
+
+    /// Find all matched texts of the pattern in the line. A line that is not valid
+    /// UTF-8 is searched in its lossy text, and the ranges of the matched texts are
+    /// moved to its original bytes.
+    #[doc(hidden)]
+    fn find_submatches(&self, line: &Line) -> Vec<Submatch> {
+        let text = line.text();
+        let mut submatches = self.matcher.find_submatches(&text);
+
+        if let Cow::Owned(_) = text {
+            let offsets = line.offsets();
+            submatches
+                .iter_mut()
+                .for_each(|submatch| submatch.move_to(&offsets));
+        }
+
+        submatches
+    }
+}
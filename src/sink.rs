@@ -0,0 +1,162 @@
+use std::io;
+use std::path::Path;
+
+use super::errors::{InvalidArgumentError, ReadError};
+use super::matching::Match;
+use super::searcher::SearchedFile;
+
+/// The consumer of the results of a [`Searcher`](crate::Searcher), receiving each
+/// event of a search with [`Searcher::search_to()`](crate::Searcher::search_to).
+///
+/// For each searched file, the sink receives the begin of the file, if the file is
+/// binary with [`BinaryPolicy::Report`](crate::BinaryPolicy::Report), each selected
+/// line and each context line in the order of the file, the line that cannot be
+/// read if the reader fails, then the end of the file. The search of a file stops
+/// at a selected line if [`Sink::matched()`] returns `false`, and the end of the
+/// file is still received. The end of the whole search is received once, after all
+/// files.
+///
+/// The printers of the [`Command`](crate::Command) are sinks writing to stdout.
+/// Only [`Sink::matched()`] must be implemented, and the other events are ignored
+/// by default. A `Vec<SearchedFile>` is a sink collecting all searched files.
+///
+/// # Returns
+///
+/// Each method returns an [`io::Error`] if the event cannot be consumed, like a
+/// closed output, and the search is stopped.
+///
+/// # Examples
+///
+/// ```rust
+/// use std::io;
+/// use std::path::{Path, PathBuf};
+///
+/// use mini_grep::{Match, Searcher, Sink};
+///
+/// /// Count the selected lines of each file.
+/// #[derive(Default)]
+/// struct Counter(Vec<(PathBuf, usize)>);
+///
+/// impl Sink for Counter {
+///     fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+///         self.0.push((path.to_path_buf(), 0));
+///         Ok(())
+///     }
+///
+///     fn matched(&mut self, _path: &Path, _found: Match) -> io::Result<bool> {
+///         if let Some((_, count)) = self.0.last_mut() {
+///             *count += 1;
+///         }
+///         Ok(true)
+///     }
+/// }
+///
+/// let searcher = Searcher::builder("is").build().unwrap();
+/// let mut counter = Counter::default();
+///
+/// searcher
+///     .search_to("text", "Rust is fast.\nC is old.\nThe end.\n".as_bytes(), &mut counter)
+///     .unwrap();
+///
+/// assert_eq!(counter.0, [(PathBuf::from("text"), 2)]);
+/// ```
+pub trait Sink {
+    /// Receive the begin of the file, before its lines.
+    fn begin_file(&mut self, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive that the file is binary, before its lines, with
+    /// [`BinaryPolicy::Report`](crate::BinaryPolicy::Report). Its lines are still
+    /// received, and the sink decides how to report them, like a printer stopping
+    /// the file at its first selected line.
+    fn binary_file(&mut self, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive a selected line of the file, with its matched texts.
+    ///
+    /// # Returns
+    ///
+    /// Returns `true` to continue the search of the file, or `false` to stop it at
+    /// this line, like a sink needing only the first selected line of each file.
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool>;
+
+    /// Receive a context line of the file, around a selected line.
+    fn context(&mut self, _path: &Path, _line: Match) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive the error of a line of the file that cannot be read, and the search
+    /// of the file stops.
+    fn read_error(&mut self, _path: &Path, _error: ReadError) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive the error of a file that cannot be opened, or of a directory that
+    /// cannot be read, in place of its search, by
+    /// [`Searcher::search_path_to()`](crate::Searcher::search_path_to) or the
+    /// [`Command`](crate::Command). The other files are still searched.
+    fn open_error(&mut self, _path: &Path, _error: InvalidArgumentError) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive the end of the file, after all its lines.
+    fn end_file(&mut self, _path: &Path) -> io::Result<()> {
+        Ok(())
+    }
+
+    /// Receive the end of the search, after all files.
+    fn finish(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+impl Sink for Vec<SearchedFile> {
+    fn begin_file(&mut self, path: &Path) -> io::Result<()> {
+        self.push(SearchedFile {
+            path: path.to_path_buf(),
+            lines: Vec::new(),
+            error: None,
+        });
+        Ok(())
+    }
+
+    fn matched(&mut self, path: &Path, found: Match) -> io::Result<bool> {
+        file(self, path).lines.push(Ok(found));
+        Ok(true)
+    }
+
+    fn context(&mut self, path: &Path, line: Match) -> io::Result<()> {
+        file(self, path).lines.push(Ok(line));
+        Ok(())
+    }
+
+    fn read_error(&mut self, path: &Path, error: ReadError) -> io::Result<()> {
+        file(self, path).lines.push(Err(error));
+        Ok(())
+    }
+
+    fn open_error(&mut self, path: &Path, error: InvalidArgumentError) -> io::Result<()> {
+        self.push(SearchedFile {
+            path: path.to_path_buf(),
+            lines: Vec::new(),
+            error: Some(error),
+        });
+        Ok(())
+    }
+}
+
+/// Get the last collected file, or a new file if the last one has another path.
+#[doc(hidden)]
+fn file<'a>(files: &'a mut Vec<SearchedFile>, path: &Path) -> &'a mut SearchedFile {
+    if files.last().map_or(true, |file| file.path != path) {
+        files.push(SearchedFile {
+            path: path.to_path_buf(),
+            lines: Vec::new(),
+            error: None,
+        });
+    }
+
+    files.last_mut().expect("A file is always collected.")
+}